@@ -0,0 +1,128 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use graphviz_dot_builder::graph::graph::GraphVizDiGraph;
+
+use crate::bre::algos::drawing::label_states_with_terms;
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::dfa::dfa::AutDFA;
+use crate::traits::letter::AutLetter;
+use crate::traits::repr::{AbstractLanguagePrinter, AutGraphvizDrawable};
+
+
+/// A DFA built from the Brzozowski derivatives of a regular expression.
+/// Each state is labelled by the derivative term which expresses the words that are accepted from that state.
+#[derive(Debug, Clone)]
+pub struct DerivativesDFA<Letter : AutLetter> {
+    pub dfa : AutDFA<Letter>,
+    pub states_terms : Vec<TermBRE<Letter>>
+}
+
+/// Builds the DFA whose states are the iterated derivatives of the term of *bre* (see "TermBRE::derivative").
+/// The initial state is labelled by the term itself and a state is final if its term expresses 𝜀.
+/// Derivatives expressing the empty language are not represented so the DFA is trimmed.
+pub fn bre_to_dfa_by_derivatives<Letter : AutLetter>(bre : &ExpBRE<Letter>) -> DerivativesDFA<Letter> {
+    let mut letters : Vec<Letter> = bre.alphabet.iter().cloned().collect();
+    letters.sort();
+    // ***
+    let mut states_terms = vec![bre.term.clone()];
+    let mut states_ids : HashMap<TermBRE<Letter>,usize> = HashMap::new();
+    states_ids.insert(bre.term.clone(),0);
+    let mut finals = HashSet::new();
+    let mut transitions = vec![HashMap::new()];
+    let mut queue = VecDeque::from([0]);
+    while let Some(orig) = queue.pop_front() {
+        let term = states_terms[orig].clone();
+        if term.expresses_epsilon() {
+            finals.insert(orig);
+        }
+        for letter in &letters {
+            let derivative = term.derivative(letter);
+            if derivative.is_empty() {
+                continue;
+            }
+            let targ = match states_ids.get(&derivative) {
+                Some(targ) => *targ,
+                None => {
+                    let targ = states_terms.len();
+                    states_ids.insert(derivative.clone(),targ);
+                    states_terms.push(derivative);
+                    transitions.push(HashMap::new());
+                    queue.push_back(targ);
+                    targ
+                }
+            };
+            transitions[orig].insert(*letter,targ);
+        }
+    }
+    // ***
+    let dfa = AutDFA::from_raw(bre.alphabet.clone(),0,finals,transitions).unwrap();
+    DerivativesDFA{dfa,states_terms}
+}
+
+
+impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for DerivativesDFA<Letter> where
+    Letter : AutLetter,
+    Printer : AbstractLanguagePrinter<Letter> {
+
+    fn to_dot(&self,
+              draw_accessibility : bool,
+              active_states : &HashSet<usize>,
+              printer : &Printer) -> GraphVizDiGraph {
+        let mut digraph = self.dfa.to_dot(draw_accessibility,active_states,printer);
+        label_states_with_terms(&mut digraph,&self.states_terms,printer);
+        digraph
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::algos::derivatives::bre_to_dfa_by_derivatives;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    /// (a|b)*.a.b
+    fn get_example() -> ExpBRE<char> {
+        let a_or_b = TermBRE::Union(BTreeSet::from([TermBRE::Literal('a'),TermBRE::Literal('b')]));
+        let term = TermBRE::Concat(vec![TermBRE::Kleene(Box::new(a_or_b)),TermBRE::Literal('a'),TermBRE::Literal('b')]);
+        ExpBRE::from_raw(hashset!{'a','b'},term).unwrap()
+    }
+
+    #[test]
+    fn derivatives_test() {
+        let bre = get_example();
+        let derivatives_dfa = bre_to_dfa_by_derivatives(&bre);
+        // the derivatives are (a|b)*.a.b, ((a|b)*.a.b)|b and ((a|b)*.a.b)|𝜀
+        assert_eq!(derivatives_dfa.states_terms.len(), 3);
+        assert_eq!(derivatives_dfa.states_terms[0], bre.term);
+        let nfa = bre.to_nfa();
+        for word in get_all_words(6) {
+            let expected = nfa.runs_trace(&word).unwrap();
+            assert_eq!(bre.matches(&word), expected);
+            assert_eq!(derivatives_dfa.dfa.runs_trace(&word).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use graphviz_dot_builder::graph::graph::GraphVizDiGraph;
+use graphviz_dot_builder::item::item::GraphVizGraphItem;
+use graphviz_dot_builder::item::node::style::GraphvizNodeStyleItem;
+
+use crate::bre::term::TermBRE;
+use crate::traits::letter::AutLetter;
+use crate::traits::repr::{AbstractLanguagePrinter, ExpBREPrintable};
+
+
+/// Completes the label of each state "S{i}" of *digraph* with the i-th term
+pub(crate) fn label_states_with_terms<Letter, Printer>(digraph : &mut GraphVizDiGraph,
+                                                       states_terms : &[TermBRE<Letter>],
+                                                       printer : &Printer) where
+    Letter : AutLetter,
+    Printer : AbstractLanguagePrinter<Letter> {
+    for item in digraph.items.iter_mut() {
+        if let GraphVizGraphItem::Node(node) = item {
+            let state = node.id.strip_prefix('S').and_then(|id| id.parse::<usize>().ok());
+            if let Some(term) = state.and_then(|state| states_terms.get(state)) {
+                let term_as_str = term.regexp_to_string(true,printer);
+                for style_item in node.style.iter_mut() {
+                    if let GraphvizNodeStyleItem::Label(label) = style_item {
+                        *label = format!("{}\\n{}",label,term_as_str);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::nfa::nfa::AutNFA;
+use crate::traits::letter::AutLetter;
+
+
+/// The Glushkov automaton of a regular expression, which has an initial state 0
+/// and one state for each occurrence of a letter in the term, numbered in the order in which they appear.
+#[derive(Debug, Clone)]
+pub struct PositionAutomaton<Letter : AutLetter> {
+    pub nfa : AutNFA<Letter>,
+    /// For each state, None for the initial state and otherwise the path from the root of the term to the occurrence of the letter
+    /// i.e. the indices of the sub-terms which are successively entered (0 for the sub-term of a Kleene star
+    /// and, for a union, the index in the order of its set of sub-terms).
+    pub states_positions : Vec<Option<Vec<usize>>>
+}
+
+impl<Letter : AutLetter> PositionAutomaton<Letter> {
+
+    /// An expression is deterministic (or one-unambiguous) if and only if its Glushkov automaton is deterministic
+    pub fn is_deterministic(&self) -> bool {
+        self.nfa.initials.len() <= 1
+            && self.nfa.transitions.iter().all(|map| map.values().all(|targets| targets.len() <= 1))
+    }
+
+}
+
+
+/// first, last and nullability of a sub-term, positions being numbered from 1
+struct PositionSets {
+    nullable : bool,
+    first : BTreeSet<usize>,
+    last : BTreeSet<usize>
+}
+
+struct Linearization<Letter : AutLetter> {
+    letters : Vec<Letter>,
+    paths : Vec<Vec<usize>>,
+    follow : Vec<BTreeSet<usize>>
+}
+
+impl<Letter : AutLetter> Linearization<Letter> {
+
+    fn analyze(&mut self, term : &TermBRE<Letter>, path : &mut Vec<usize>) -> PositionSets {
+        match term {
+            TermBRE::Empty => PositionSets{nullable:false,first:BTreeSet::new(),last:BTreeSet::new()},
+            TermBRE::Epsilon => PositionSets{nullable:true,first:BTreeSet::new(),last:BTreeSet::new()},
+            TermBRE::Literal(letter) => {
+                self.letters.push(*letter);
+                self.paths.push(path.clone());
+                self.follow.push(BTreeSet::new());
+                let position = self.letters.len();
+                PositionSets{nullable:false,first:BTreeSet::from([position]),last:BTreeSet::from([position])}
+            },
+            TermBRE::Union(sub_terms) => {
+                let mut sets = PositionSets{nullable:false,first:BTreeSet::new(),last:BTreeSet::new()};
+                for (index,sub_term) in sub_terms.iter().enumerate() {
+                    path.push(index);
+                    let sub_sets = self.analyze(sub_term,path);
+                    path.pop();
+                    sets.nullable = sets.nullable || sub_sets.nullable;
+                    sets.first.extend(sub_sets.first);
+                    sets.last.extend(sub_sets.last);
+                }
+                sets
+            },
+            TermBRE::Concat(sub_terms) => {
+                let mut sets = PositionSets{nullable:true,first:BTreeSet::new(),last:BTreeSet::new()};
+                for (index,sub_term) in sub_terms.iter().enumerate() {
+                    path.push(index);
+                    let sub_sets = self.analyze(sub_term,path);
+                    path.pop();
+                    for position in &sets.last {
+                        self.follow[position - 1].extend(sub_sets.first.iter().cloned());
+                    }
+                    if sets.nullable {
+                        sets.first.extend(sub_sets.first.iter().cloned());
+                    }
+                    if sub_sets.nullable {
+                        sets.last.extend(sub_sets.last);
+                    } else {
+                        sets.last = sub_sets.last;
+                    }
+                    sets.nullable = sets.nullable && sub_sets.nullable;
+                }
+                sets
+            },
+            TermBRE::Kleene(sub_term) => {
+                path.push(0);
+                let sub_sets = self.analyze(sub_term,path);
+                path.pop();
+                for position in &sub_sets.last {
+                    self.follow[position - 1].extend(sub_sets.first.iter().cloned());
+                }
+                PositionSets{nullable:true,first:sub_sets.first,last:sub_sets.last}
+            }
+        }
+    }
+
+}
+
+
+/// Builds the Glushkov automaton of *bre* from the first, last and follow sets of the positions of its letters.
+/// There is a transition from the initial state to each position in first
+/// and from each position to each position which follows it, labelled by the letter of the target.
+/// The final states are the positions in last and the initial state if the term expresses 𝜀.
+pub fn bre_to_position_automaton<Letter : AutLetter>(bre : &ExpBRE<Letter>) -> PositionAutomaton<Letter> {
+    let mut linearization = Linearization{letters:vec![],paths:vec![],follow:vec![]};
+    let sets = linearization.analyze(&bre.term,&mut vec![]);
+    // ***
+    let into_transitions = |positions : &BTreeSet<usize>| -> HashMap<Letter,HashSet<usize>> {
+        let mut transitions : HashMap<Letter,HashSet<usize>> = HashMap::new();
+        for position in positions {
+            transitions.entry(linearization.letters[position - 1]).or_default().insert(*position);
+        }
+        transitions
+    };
+    let mut transitions = vec![into_transitions(&sets.first)];
+    transitions.extend(linearization.follow.iter().map(into_transitions));
+    let mut finals : HashSet<usize> = sets.last.into_iter().collect();
+    if sets.nullable {
+        finals.insert(0);
+    }
+    // ***
+    let nfa = AutNFA::from_raw(bre.alphabet.clone(),HashSet::from([0]),finals,transitions).unwrap();
+    let mut states_positions = vec![None];
+    states_positions.extend(linearization.paths.into_iter().map(Some));
+    PositionAutomaton{nfa,states_positions}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::algos::glushkov::bre_to_position_automaton;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::traits::run::AutRunnable;
+    use crate::tests_utils::get_all_words;
+
+    #[test]
+    fn position_automaton_test() {
+        // (a.b|b)*.a.(𝜀|b)
+        let ab_or_b = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![TermBRE::Literal('a'),TermBRE::Literal('b')]),
+            TermBRE::Literal('b')]));
+        let eps_or_b = TermBRE::Union(BTreeSet::from([TermBRE::Epsilon,TermBRE::Literal('b')]));
+        let term = TermBRE::Concat(vec![TermBRE::Kleene(Box::new(ab_or_b)),TermBRE::Literal('a'),eps_or_b]);
+        let bre = ExpBRE::from_raw(hashset!{'a','b'},term).unwrap();
+        let glushkov = bre_to_position_automaton(&bre);
+        assert_eq!(glushkov.nfa.transitions.len(), 6);
+        // the union in the Kleene star is ordered as b,(a.b) so that 'b' is its first sub-term
+        assert_eq!(glushkov.states_positions[1], Some(vec![0,0,0]));
+        assert_eq!(glushkov.states_positions[2], Some(vec![0,0,1,0]));
+        assert_eq!(glushkov.states_positions[4], Some(vec![1]));
+        // 'a' may be read from the first or second literal a
+        assert!(!glushkov.is_deterministic());
+        for word in get_all_words(7) {
+            assert_eq!(glushkov.nfa.runs_trace(&word).unwrap(), bre.matches(&word));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use graphviz_dot_builder::graph::graph::GraphVizDiGraph;
+
+use crate::bre::algos::drawing::label_states_with_terms;
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::nfa::nfa::AutNFA;
+use crate::traits::letter::AutLetter;
+use crate::traits::repr::{AbstractLanguagePrinter, AutGraphvizDrawable};
+
+
+/// An epsilon-free NFA built from the Antimirov partial derivatives of a regular expression.
+/// Each state is labelled by the partial derivative term which expresses the words that are accepted from that state.
+#[derive(Debug, Clone)]
+pub struct PartialDerivativesNFA<Letter : AutLetter> {
+    pub nfa : AutNFA<Letter>,
+    pub states_terms : Vec<TermBRE<Letter>>
+}
+
+/// Builds the NFA whose states are the iterated partial derivatives of the term of *bre* (see "TermBRE::partial_derivatives").
+/// The single initial state is labelled by the term itself and a state is final if its term expresses 𝜀.
+/// There are at most as many states as there are occurrences of letters in the term plus one.
+pub fn bre_to_nfa_by_partial_derivatives<Letter : AutLetter>(bre : &ExpBRE<Letter>) -> PartialDerivativesNFA<Letter> {
+    let mut letters : Vec<Letter> = bre.alphabet.iter().cloned().collect();
+    letters.sort();
+    // ***
+    let mut states_terms = vec![bre.term.clone()];
+    let mut states_ids : HashMap<TermBRE<Letter>,usize> = HashMap::new();
+    states_ids.insert(bre.term.clone(),0);
+    let mut finals = HashSet::new();
+    let mut transitions = vec![HashMap::new()];
+    let mut queue = VecDeque::from([0]);
+    while let Some(orig) = queue.pop_front() {
+        let term = states_terms[orig].clone();
+        if term.expresses_epsilon() {
+            finals.insert(orig);
+        }
+        for letter in &letters {
+            let mut targets = HashSet::new();
+            for derivative in term.partial_derivatives(letter) {
+                if derivative.is_empty() {
+                    continue;
+                }
+                let targ = match states_ids.get(&derivative) {
+                    Some(targ) => *targ,
+                    None => {
+                        let targ = states_terms.len();
+                        states_ids.insert(derivative.clone(),targ);
+                        states_terms.push(derivative);
+                        transitions.push(HashMap::new());
+                        queue.push_back(targ);
+                        targ
+                    }
+                };
+                targets.insert(targ);
+            }
+            if !targets.is_empty() {
+                transitions[orig].insert(*letter,targets);
+            }
+        }
+    }
+    // ***
+    let nfa = AutNFA::from_raw(bre.alphabet.clone(),HashSet::from([0]),finals,transitions).unwrap();
+    PartialDerivativesNFA{nfa,states_terms}
+}
+
+
+impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for PartialDerivativesNFA<Letter> where
+    Letter : AutLetter,
+    Printer : AbstractLanguagePrinter<Letter> {
+
+    fn to_dot(&self,
+              draw_accessibility : bool,
+              active_states : &HashSet<usize>,
+              printer : &Printer) -> GraphVizDiGraph {
+        let mut digraph = self.nfa.to_dot(draw_accessibility,active_states,printer);
+        label_states_with_terms(&mut digraph,&self.states_terms,printer);
+        digraph
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::algos::partial_derivatives::bre_to_nfa_by_partial_derivatives;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::traits::run::AutRunnable;
+    use crate::tests_utils::get_all_words;
+
+    /// (a.b|a)*.b.(a|b)
+    fn get_example() -> ExpBRE<char> {
+        let ab_or_a = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![TermBRE::Literal('a'),TermBRE::Literal('b')]),
+            TermBRE::Literal('a')]));
+        let a_or_b = TermBRE::Union(BTreeSet::from([TermBRE::Literal('a'),TermBRE::Literal('b')]));
+        let term = TermBRE::Concat(vec![TermBRE::Kleene(Box::new(ab_or_a)),TermBRE::Literal('b'),a_or_b]);
+        ExpBRE::from_raw(hashset!{'a','b'},term).unwrap()
+    }
+
+    #[test]
+    fn partial_derivatives_test() {
+        let bre = get_example();
+        let antimirov = bre_to_nfa_by_partial_derivatives(&bre);
+        // there are 6 occurrences of letters
+        assert!(antimirov.states_terms.len() <= 7);
+        assert_eq!(antimirov.states_terms[0], bre.term);
+        for word in get_all_words(7) {
+            assert_eq!(antimirov.nfa.runs_trace(&word).unwrap(), bre.matches(&word));
+        }
+    }
+}
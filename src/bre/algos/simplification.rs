@@ -0,0 +1,257 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::bre::term::TermBRE;
+use crate::traits::letter::AutLetter;
+
+
+/// Rewrites *term* into an equivalent term using laws of Kleene algebra until a fixpoint is reached.
+///
+/// At each pass, the following rewritings are applied bottom-up:
+/// - ∅ and 𝜀 are removed from unions and concatenations and nested unions and concatenations are flattened
+/// - x|x* → x* and 𝜀|x → x whenever x expresses 𝜀
+/// - common prefixes and suffixes of the members of unions are factored e.g. a.b|a.c → a.(b|c)
+/// - x*.x* → x*
+/// - ∅* → 𝜀, 𝜀* → 𝜀 and (x*)* → x*
+/// - (𝜀|x|y*)* → (x|y)* and (x.y)* → (x|y)* whenever both x and y express 𝜀
+///
+/// None of those rewritings increases the size of the term (see "TermBRE::size") and
+/// factorizations are only applied when they strictly decrease it.
+/// The driver stops as soon as a pass leaves the term unchanged or yields a term which has already been met,
+/// so the result is never larger than *term*.
+pub fn simplify_term_bre<Letter : AutLetter>(term : &TermBRE<Letter>) -> TermBRE<Letter> {
+    let mut current = term.clone();
+    let mut met : HashSet<TermBRE<Letter>> = HashSet::new();
+    met.insert(current.clone());
+    loop {
+        let next = rewrite(&current);
+        if next.size() > current.size() || !met.insert(next.clone()) {
+            return current;
+        }
+        current = next;
+    }
+}
+
+
+fn rewrite<Letter : AutLetter>(term : &TermBRE<Letter>) -> TermBRE<Letter> {
+    match term {
+        TermBRE::Union(sub_terms) => {
+            rewrite_union(sub_terms.iter().map(rewrite).collect())
+        },
+        TermBRE::Concat(sub_terms) => {
+            rewrite_concat(sub_terms.iter().map(rewrite).collect())
+        },
+        TermBRE::Kleene(sub_term) => {
+            rewrite_kleene(rewrite(sub_term))
+        },
+        _ => term.clone()
+    }
+}
+
+fn rewrite_union<Letter : AutLetter>(members : Vec<TermBRE<Letter>>) -> TermBRE<Letter> {
+    let mut set = BTreeSet::new();
+    for member in members {
+        match member {
+            TermBRE::Empty => {},
+            TermBRE::Union(sub_members) => {
+                set.extend(sub_members);
+            },
+            _ => {
+                set.insert(member);
+            }
+        }
+    }
+    // x|x* → x*
+    let starred : Vec<TermBRE<Letter>> = set.iter()
+        .filter_map(|member| match member {
+            TermBRE::Kleene(sub_term) => Some((**sub_term).clone()),
+            _ => None
+        }).collect();
+    for sub_term in starred {
+        set.remove(&sub_term);
+    }
+    // 𝜀|x → x whenever x expresses 𝜀
+    if set.contains(&TermBRE::Epsilon) && set.iter().any(|m| *m != TermBRE::Epsilon && m.expresses_epsilon()) {
+        set.remove(&TermBRE::Epsilon);
+    }
+    // ***
+    let set = factor_members(set,true);
+    let mut set = factor_members(set,false);
+    match set.len() {
+        0 => TermBRE::Empty,
+        1 => set.pop_first().unwrap(),
+        _ => TermBRE::Union(set)
+    }
+}
+
+/// A member of a union along with what remains of it once its first or last factor is removed
+type MemberAndRest<Letter> = (TermBRE<Letter>,TermBRE<Letter>);
+
+/// Factors the common first (if *prefix*) or last factor of the members of a union
+/// when this decreases the total size of the members
+fn factor_members<Letter : AutLetter>(set : BTreeSet<TermBRE<Letter>>,
+                                      prefix : bool) -> BTreeSet<TermBRE<Letter>> {
+    let mut groups : BTreeMap<TermBRE<Letter>,Vec<MemberAndRest<Letter>>> = BTreeMap::new();
+    for member in set {
+        let mut factors = match &member {
+            TermBRE::Concat(factors) => factors.clone(),
+            _ => vec![member.clone()]
+        };
+        let affix = if prefix {factors.remove(0)} else {factors.pop().unwrap()};
+        let rest = rewrite_concat(factors);
+        groups.entry(affix).or_default().push((member,rest));
+    }
+    // ***
+    let mut new_set = BTreeSet::new();
+    for (affix,group) in groups {
+        if group.len() > 1 {
+            let rests = rewrite_union(group.iter().map(|(_,rest)| rest.clone()).collect());
+            let factored = if prefix {
+                rewrite_concat(vec![affix,rests])
+            } else {
+                rewrite_concat(vec![rests,affix])
+            };
+            if factored.size() < group.iter().map(|(member,_)| member.size()).sum() {
+                new_set.insert(factored);
+                continue;
+            }
+        }
+        new_set.extend(group.into_iter().map(|(member,_)| member));
+    }
+    new_set
+}
+
+fn rewrite_concat<Letter : AutLetter>(factors : Vec<TermBRE<Letter>>) -> TermBRE<Letter> {
+    let mut new_factors : Vec<TermBRE<Letter>> = vec![];
+    for factor in factors {
+        let sub_factors = match factor {
+            TermBRE::Empty => {
+                return TermBRE::Empty;
+            },
+            TermBRE::Epsilon => {
+                vec![]
+            },
+            TermBRE::Concat(sub_factors) => {
+                sub_factors
+            },
+            _ => {
+                vec![factor]
+            }
+        };
+        for sub_factor in sub_factors {
+            // x*.x* → x*
+            if let (TermBRE::Kleene(_),Some(last)) = (&sub_factor,new_factors.last()) {
+                if *last == sub_factor {
+                    continue;
+                }
+            }
+            new_factors.push(sub_factor);
+        }
+    }
+    match new_factors.len() {
+        0 => TermBRE::Epsilon,
+        1 => new_factors.pop().unwrap(),
+        _ => TermBRE::Concat(new_factors)
+    }
+}
+
+fn rewrite_kleene<Letter : AutLetter>(sub_term : TermBRE<Letter>) -> TermBRE<Letter> {
+    let members = match sub_term {
+        TermBRE::Empty | TermBRE::Epsilon => {
+            return TermBRE::Epsilon;
+        },
+        TermBRE::Kleene(_) => {
+            return sub_term;
+        },
+        TermBRE::Union(members) => {
+            members.into_iter().collect()
+        },
+        TermBRE::Concat(factors) if factors.iter().all(|f| f.expresses_epsilon()) => {
+            factors
+        },
+        _ => {
+            return TermBRE::Kleene(Box::new(sub_term));
+        }
+    };
+    // under a star, 𝜀 can be removed from a union and its members need not be starred
+    let members = members.into_iter()
+        .filter(|member| *member != TermBRE::Epsilon)
+        .map(|member| match member {
+            TermBRE::Kleene(sub_member) => *sub_member,
+            _ => member
+        }).collect();
+    match rewrite_union(members) {
+        TermBRE::Empty | TermBRE::Epsilon => TermBRE::Epsilon,
+        TermBRE::Kleene(sub_member) => TermBRE::Kleene(sub_member),
+        inner => TermBRE::Kleene(Box::new(inner))
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::algos::simplification::simplify_term_bre;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::random::params::RandomTermParameters;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::tests_utils::for_each_random_term;
+
+    fn star(term : TermBRE<char>) -> TermBRE<char> {
+        TermBRE::Kleene(Box::new(term))
+    }
+
+    #[test]
+    fn simplification_laws_test() {
+        let a = TermBRE::Literal('a');
+        let b = TermBRE::Literal('b');
+        let c = TermBRE::Literal('c');
+        // (a*)* → a*
+        assert_eq!(simplify_term_bre(&star(star(a.clone()))), star(a.clone()));
+        // 𝜀|a* → a*
+        let term = TermBRE::Union(BTreeSet::from([TermBRE::Epsilon,star(a.clone())]));
+        assert_eq!(simplify_term_bre(&term), star(a.clone()));
+        // (a|𝜀)* → a*
+        let term = star(TermBRE::Union(BTreeSet::from([TermBRE::Epsilon,a.clone()])));
+        assert_eq!(simplify_term_bre(&term), star(a.clone()));
+        // (a*.b*)* → (a|b)*
+        let term = star(TermBRE::Concat(vec![star(a.clone()),star(b.clone())]));
+        assert_eq!(simplify_term_bre(&term), star(TermBRE::Union(BTreeSet::from([a.clone(),b.clone()]))));
+        // a.b.c|a.c.c → a.(b|c).c
+        let term = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![a.clone(),b.clone(),c.clone()]),
+            TermBRE::Concat(vec![a.clone(),c.clone(),c.clone()])]));
+        let expected = TermBRE::Concat(vec![a,TermBRE::Union(BTreeSet::from([b,c.clone()])),c]);
+        assert_eq!(simplify_term_bre(&term), expected);
+    }
+
+    #[test]
+    fn simplification_preserves_language_test() {
+        let params = RandomTermParameters::new(hashset!{'a','b'},5,15);
+        for_each_random_term(15,50,&params,|term| {
+            let simplified = simplify_term_bre(&term);
+            assert!(simplified.size() <= term.size());
+            let original = ExpBRE::from_raw(hashset!{'a','b'},term).unwrap();
+            let simplified = ExpBRE::from_raw(hashset!{'a','b'},simplified).unwrap();
+            assert!(original.equals(&simplified));
+        });
+    }
+}
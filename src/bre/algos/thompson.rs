@@ -0,0 +1,152 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet};
+
+use crate::bre::term::TermBRE;
+use crate::nfait::nfait::AutNFAIT;
+use crate::traits::letter::AutLetter;
+
+
+/// Builds Thompson fragments for terms within the states of an automaton with immediate transitions.
+/// Each fragment has a single entry state and a single exit state and at most two states per node of the term.
+pub(crate) struct ThompsonBuilder<Letter : AutLetter> {
+    pub transitions : Vec<HashMap<Letter,HashSet<usize>>>,
+    pub epsilon_trans : Vec<HashSet<usize>>
+}
+
+impl<Letter : AutLetter> ThompsonBuilder<Letter> {
+
+    pub fn new(states_num : usize) -> Self {
+        ThompsonBuilder{transitions:vec![HashMap::new();states_num],epsilon_trans:vec![HashSet::new();states_num]}
+    }
+
+    pub fn add_state(&mut self) -> usize {
+        self.transitions.push(HashMap::new());
+        self.epsilon_trans.push(HashSet::new());
+        self.transitions.len() - 1
+    }
+
+    pub fn add_epsilon(&mut self, orig : usize, targ : usize) {
+        self.epsilon_trans[orig].insert(targ);
+    }
+
+    /// Adds a fragment for *term* and returns its entry and exit states
+    pub fn add_term(&mut self, term : &TermBRE<Letter>) -> (usize,usize) {
+        match term {
+            TermBRE::Empty => {
+                (self.add_state(),self.add_state())
+            },
+            TermBRE::Epsilon => {
+                let entry = self.add_state();
+                let exit = self.add_state();
+                self.add_epsilon(entry,exit);
+                (entry,exit)
+            },
+            TermBRE::Literal(letter) => {
+                let entry = self.add_state();
+                let exit = self.add_state();
+                self.transitions[entry].entry(*letter).or_default().insert(exit);
+                (entry,exit)
+            },
+            TermBRE::Union(sub_terms) => {
+                let entry = self.add_state();
+                let exit = self.add_state();
+                for sub_term in sub_terms {
+                    let (sub_entry,sub_exit) = self.add_term(sub_term);
+                    self.add_epsilon(entry,sub_entry);
+                    self.add_epsilon(sub_exit,exit);
+                }
+                (entry,exit)
+            },
+            TermBRE::Concat(sub_terms) => {
+                let mut fragments = sub_terms.iter().map(|sub_term| self.add_term(sub_term)).collect::<Vec<(usize,usize)>>().into_iter();
+                match fragments.next() {
+                    None => self.add_term(&TermBRE::Epsilon),
+                    Some((entry,mut exit)) => {
+                        for (sub_entry,sub_exit) in fragments {
+                            self.add_epsilon(exit,sub_entry);
+                            exit = sub_exit;
+                        }
+                        (entry,exit)
+                    }
+                }
+            },
+            TermBRE::Kleene(sub_term) => {
+                let entry = self.add_state();
+                let (sub_entry,sub_exit) = self.add_term(sub_term);
+                let exit = self.add_state();
+                self.add_epsilon(entry,sub_entry);
+                self.add_epsilon(entry,exit);
+                self.add_epsilon(sub_exit,sub_entry);
+                self.add_epsilon(sub_exit,exit);
+                (entry,exit)
+            }
+        }
+    }
+
+}
+
+
+/// Thompson's construction of an automaton with immediate transitions from *term*,
+/// with a single initial state and a single final state and a number of states linear in the size of the term.
+pub fn term_bre_to_nfait_by_thompson<Letter : AutLetter>(term : &TermBRE<Letter>,
+                                                         alphabet : &HashSet<Letter>) -> AutNFAIT<Letter> {
+    let mut builder = ThompsonBuilder::new(0);
+    let (entry,exit) = builder.add_term(term);
+    AutNFAIT::from_raw(alphabet.clone(),
+                       HashSet::from([entry]),
+                       HashSet::from([exit]),
+                       builder.transitions,
+                       builder.epsilon_trans).unwrap()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::algos::thompson::term_bre_to_nfait_by_thompson;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::transform::AutTransformable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    #[test]
+    fn thompson_test() {
+        // (a.b|𝜀)*.b.(a|∅)
+        let ab_or_eps = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![TermBRE::Literal('a'),TermBRE::Literal('b')]),
+            TermBRE::Epsilon]));
+        let a_or_empty = TermBRE::Union(BTreeSet::from([TermBRE::Literal('a'),TermBRE::Empty]));
+        let term = TermBRE::Concat(vec![TermBRE::Kleene(Box::new(ab_or_eps)),TermBRE::Literal('b'),a_or_empty]);
+        let bre = ExpBRE::from_raw(hashset!{'a','b'},term).unwrap();
+        let nfait = term_bre_to_nfait_by_thompson(&bre.term,&bre.alphabet);
+        // 11 nodes in the term
+        assert!(nfait.transitions.len() <= 22);
+        let reversed = nfait.clone().reverse();
+        let from_gnfa = bre.to_gnfa().to_nfait();
+        for word in get_all_words(7) {
+            assert_eq!(nfait.runs_trace(&word).unwrap(), bre.matches(&word));
+            assert_eq!(from_gnfa.runs_trace(&word).unwrap(), bre.matches(&word));
+            let reversed_word : Vec<char> = word.iter().rev().cloned().collect();
+            assert_eq!(reversed.runs_trace(&reversed_word).unwrap(), bre.matches(&word));
+        }
+    }
+}
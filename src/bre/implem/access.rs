@@ -0,0 +1,180 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::traits::access::AutAccessible;
+use crate::traits::letter::AutLetter;
+
+
+impl<Letter : AutLetter> TermBRE<Letter> {
+
+    /// Replaces by ∅ the occurrences of letters whose positions (numbered from 1 in the order in which they appear) are not kept
+    fn remove_positions(&self,
+                        kept : &HashSet<usize>,
+                        next_position : &mut usize) -> Self {
+        match self {
+            TermBRE::Literal(_) => {
+                *next_position += 1;
+                if kept.contains(next_position) {
+                    self.clone()
+                } else {
+                    TermBRE::Empty
+                }
+            },
+            TermBRE::Union(sub_terms) => {
+                let new_sub_terms : BTreeSet<TermBRE<Letter>> = sub_terms.iter()
+                    .map(|t| t.remove_positions(kept,next_position))
+                    .collect();
+                TermBRE::Union(new_sub_terms)
+            },
+            TermBRE::Concat(sub_terms) => {
+                TermBRE::Concat(sub_terms.iter().map(|t| t.remove_positions(kept,next_position)).collect())
+            },
+            TermBRE::Kleene(sub_term) => {
+                TermBRE::Kleene(Box::new(sub_term.remove_positions(kept,next_position)))
+            },
+            _ => self.clone()
+        }
+    }
+
+}
+
+
+/// The states of an expression are those of its Glushkov automaton (see "ExpBRE::to_position_automaton")
+/// i.e. the initial state 0 and one state per occurrence of a letter.
+/// Removing a state replaces the corresponding occurrence by ∅.
+impl<Letter: AutLetter> AutAccessible for ExpBRE<Letter> {
+
+    fn is_accessible(&self) -> bool {
+        self.get_all_accessible_states().len() == self.to_position_automaton().nfa.transitions.len()
+    }
+
+    fn get_all_accessible_states(&self) -> HashSet<usize> {
+        self.to_position_automaton().nfa.get_all_accessible_states()
+    }
+
+    fn make_accessible(mut self) -> Self {
+        let accessible = self.get_all_accessible_states();
+        self.term = self.term.remove_positions(&accessible,&mut 0);
+        self
+    }
+
+    fn is_coaccessible(&self) -> bool {
+        self.get_all_coaccessible_states().len() == self.to_position_automaton().nfa.transitions.len()
+    }
+
+    fn get_all_coaccessible_states(&self) -> HashSet<usize> {
+        self.to_position_automaton().nfa.get_all_coaccessible_states()
+    }
+
+    fn make_coaccessible(mut self) -> Self {
+        let coaccessible = self.get_all_coaccessible_states();
+        self.term = self.term.remove_positions(&coaccessible,&mut 0);
+        self
+    }
+
+    fn is_trimmed(&self) -> bool {
+        self.is_accessible() && self.is_coaccessible()
+    }
+
+    fn trim(self) -> Self {
+        self.make_accessible().make_coaccessible()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap, HashSet};
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::random::params::RandomTermParameters;
+    use crate::traits::access::AutAccessible;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::tests_utils::for_each_random_terms;
+
+    /// the sorted letters of the positions of the Glushkov automaton of *bre* which are both accessible and coaccessible
+    fn get_useful_letters(bre : &ExpBRE<char>) -> Vec<char> {
+        let nfa = bre.to_position_automaton().nfa;
+        let useful : HashSet<usize> = nfa.get_all_accessible_states()
+            .intersection(&nfa.get_all_coaccessible_states()).cloned().collect();
+        // each position is only reached by transitions labelled by its letter
+        let mut position_letters : HashMap<usize,char> = HashMap::new();
+        for map in &nfa.transitions {
+            for (letter,targets) in map {
+                for target in targets.intersection(&useful) {
+                    position_letters.insert(*target,*letter);
+                }
+            }
+        }
+        let mut letters : Vec<char> = position_letters.into_values().collect();
+        letters.sort();
+        letters
+    }
+
+    #[test]
+    fn bre_trim_test() {
+        // (a.∅|b).c | ∅.a
+        let a_then_empty = TermBRE::Concat(vec![TermBRE::Literal('a'),TermBRE::Empty]);
+        let term = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![TermBRE::Union(BTreeSet::from([a_then_empty,TermBRE::Literal('b')])),TermBRE::Literal('c')]),
+            TermBRE::Concat(vec![TermBRE::Empty,TermBRE::Literal('a')])]));
+        let bre = ExpBRE::from_raw(hashset!{'a','b','c'},term).unwrap();
+        assert_eq!(bre.get_all_accessible_states().len(), 4);
+        assert_eq!(bre.get_all_coaccessible_states().len(), 4);
+        // ***
+        let accessible = bre.clone().make_accessible();
+        assert!(accessible.is_accessible());
+        assert!(!accessible.is_coaccessible());
+        assert!(accessible.equals(&bre));
+        let coaccessible = bre.clone().make_coaccessible();
+        assert!(coaccessible.is_coaccessible());
+        assert!(!coaccessible.is_accessible());
+        assert!(coaccessible.equals(&bre));
+        // ***
+        let trimmed = bre.clone().trim();
+        assert!(trimmed.is_trimmed());
+        assert!(trimmed.equals(&bre));
+        assert_eq!(trimmed.to_position_automaton().nfa.transitions.len(), 3);
+        assert_eq!(get_useful_letters(&trimmed), vec!['b','c']);
+    }
+
+    #[test]
+    fn bre_random_trim_test() {
+        let params = RandomTermParameters::new(hashset!{'a','b'},3,8);
+        for_each_random_terms(5,30,&params,|[useful,dead,inaccessible]| {
+            // the positions of "dead" are not coaccessible and those of "inaccessible" are not accessible
+            let term = TermBRE::Union(BTreeSet::from([
+                useful,
+                TermBRE::Concat(vec![dead,TermBRE::Empty,inaccessible])]));
+            let bre = ExpBRE::from_raw(hashset!{'a','b'},term).unwrap();
+            let trimmed = bre.clone().trim();
+            assert!(trimmed.equals(&bre));
+            assert!(trimmed.is_trimmed());
+            // the positions which are kept are exactly the useful states of the Glushkov automaton
+            let useful_letters = get_useful_letters(&bre);
+            assert_eq!(trimmed.to_position_automaton().nfa.transitions.len(), useful_letters.len() + 1);
+            assert_eq!(get_useful_letters(&trimmed), useful_letters);
+        });
+    }
+}
@@ -54,8 +54,8 @@ impl<Letter: AutLetter> AutBuildable<Letter> for ExpBRE<Letter> {
 
     fn kleene(mut self) -> Self {
         self.term = match self.term {
-            TermBRE::Empty => TermBRE::Empty,
-            TermBRE::Epsilon => TermBRE::Epsilon,
+            // ∅* = 𝜀* = 𝜀
+            TermBRE::Empty | TermBRE::Epsilon => TermBRE::Epsilon,
             TermBRE::Kleene(t) => TermBRE::Kleene(t),
             _ => TermBRE::Kleene(Box::new(self.term))
         };
@@ -100,3 +100,21 @@ impl<Letter: AutLetter> AutBuildable<Letter> for ExpBRE<Letter> {
 
 }
 
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::traits::build::AutBuildable;
+
+    #[test]
+    fn bre_kleene_of_empty_test() {
+        let empty = ExpBRE::from_raw(hashset!{'a','b'},TermBRE::Empty).unwrap();
+        assert_eq!(empty.clone().kleene().term, TermBRE::Epsilon);
+        assert_eq!(empty.clone().at_least(0).term, TermBRE::Epsilon);
+        assert_eq!(empty.clone().repeat_range(0..).term, TermBRE::Epsilon);
+        assert_eq!(empty.at_least(1).term, TermBRE::Empty);
+    }
+}
@@ -0,0 +1,86 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::bre::bre::ExpBRE;
+use crate::traits::characterize::AutCharacterizable;
+use crate::traits::letter::AutLetter;
+use crate::traits::translate::AutTranslatable;
+
+
+impl<Letter: AutLetter> AutCharacterizable<Letter> for ExpBRE<Letter> {
+
+    fn is_complete(&self) -> bool {
+        self.to_nfa().is_complete()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.term.is_empty()
+    }
+
+    fn is_universal(&self) -> bool {
+        self.to_nfa().is_universal()
+    }
+
+    fn contains(&self,
+                other: &Self) -> bool {
+        self.to_nfa().contains(&other.to_nfa())
+    }
+
+    fn find_accepted_word(&self) -> Option<Vec<Letter>> {
+        self.to_nfa().find_accepted_word()
+    }
+
+    fn find_rejected_word(&self) -> Option<Vec<Letter>> {
+        self.to_nfa().find_rejected_word()
+    }
+
+    fn find_inclusion_counterexample(&self,
+                                     other: &Self) -> Option<Vec<Letter>> {
+        self.to_nfa().find_inclusion_counterexample(&other.to_nfa())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::{check_witness_words, WITNESS_TEST_LANGUAGES};
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn bre_witness_words_test() {
+        let alphabet = hashset!{'a','b'};
+        let languages : Vec<ExpBRE<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap())
+            .collect();
+        for aut1 in &languages {
+            for aut2 in &languages {
+                check_witness_words(aut1,aut2,&|aut,word| aut.to_nfa().runs_trace(word).unwrap(),4);
+            }
+        }
+        // no witness for the empty language, the universal language and included languages
+        assert_eq!(languages[0].find_accepted_word(), None);
+        assert_eq!(languages[1].find_rejected_word(), None);
+        assert_eq!(languages[4].find_inclusion_counterexample(&languages[3]), None);
+        assert_eq!(languages[1].find_inclusion_counterexample(&languages[6]), None);
+        assert_eq!(languages[3].find_inclusion_counterexample(&languages[4]).map(|word| word.len()), Some(3));
+        assert_eq!(languages[6].find_rejected_word(), Some(vec!['b','a']));
+    }
+
+}
@@ -0,0 +1,170 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use crate::bre::algos::derivatives::{bre_to_dfa_by_derivatives, DerivativesDFA};
+use crate::bre::algos::partial_derivatives::{bre_to_nfa_by_partial_derivatives, PartialDerivativesNFA};
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::traits::letter::AutLetter;
+
+
+impl<Letter : AutLetter> TermBRE<Letter> {
+
+    /// Returns the Brzozowski derivative of the term w.r.t. *letter* i.e. a term expressing the words w such that the term expresses *letter*.w
+    ///
+    /// Unions are normalized modulo associativity, commutativity and idempotence
+    /// (and the neutral and absorbing elements ∅ and 𝜀 are removed from unions and concatenations)
+    /// so that a term only has a finite number of distinct iterated derivatives.
+    pub fn derivative(&self, letter : &Letter) -> Self {
+        match self {
+            TermBRE::Empty => TermBRE::Empty,
+            TermBRE::Epsilon => TermBRE::Empty,
+            TermBRE::Literal(got) => {
+                if got == letter {
+                    TermBRE::Epsilon
+                } else {
+                    TermBRE::Empty
+                }
+            },
+            TermBRE::Union(sub_terms) => {
+                aci_union(sub_terms.iter().map(|t| t.derivative(letter)))
+            },
+            TermBRE::Concat(sub_terms) => {
+                match sub_terms.split_first() {
+                    None => TermBRE::Empty,
+                    Some((first,rest)) => {
+                        let rest = match rest.len() {
+                            0 => TermBRE::Epsilon,
+                            1 => rest[0].clone(),
+                            _ => TermBRE::Concat(rest.to_vec())
+                        };
+                        let first_derivative = first.derivative(letter).concatenate(rest.clone());
+                        if first.expresses_epsilon() {
+                            aci_union(vec![first_derivative,rest.derivative(letter)].into_iter())
+                        } else {
+                            first_derivative
+                        }
+                    }
+                }
+            },
+            TermBRE::Kleene(sub_term) => {
+                sub_term.derivative(letter).concatenate(self.clone())
+            }
+        }
+    }
+
+
+    /// Returns the Antimirov partial derivatives of the term w.r.t. *letter*
+    /// i.e. a set of terms whose union expresses the same language as the Brzozowski derivative.
+    /// Terms expressing the empty language are not included.
+    pub fn partial_derivatives(&self, letter : &Letter) -> BTreeSet<Self> {
+        match self {
+            TermBRE::Empty => BTreeSet::new(),
+            TermBRE::Epsilon => BTreeSet::new(),
+            TermBRE::Literal(got) => {
+                if got == letter {
+                    BTreeSet::from([TermBRE::Epsilon])
+                } else {
+                    BTreeSet::new()
+                }
+            },
+            TermBRE::Union(sub_terms) => {
+                sub_terms.iter().flat_map(|t| t.partial_derivatives(letter)).collect()
+            },
+            TermBRE::Concat(sub_terms) => {
+                match sub_terms.split_first() {
+                    None => BTreeSet::new(),
+                    Some((first,rest)) => {
+                        let rest = match rest.len() {
+                            0 => TermBRE::Epsilon,
+                            1 => rest[0].clone(),
+                            _ => TermBRE::Concat(rest.to_vec())
+                        };
+                        if rest.is_empty() {
+                            return BTreeSet::new();
+                        }
+                        let mut derivatives : BTreeSet<Self> = first.partial_derivatives(letter)
+                            .into_iter()
+                            .map(|t| t.concatenate(rest.clone()))
+                            .collect();
+                        if first.expresses_epsilon() {
+                            derivatives.extend(rest.partial_derivatives(letter));
+                        }
+                        derivatives
+                    }
+                }
+            },
+            TermBRE::Kleene(sub_term) => {
+                sub_term.partial_derivatives(letter)
+                    .into_iter()
+                    .map(|t| t.concatenate(self.clone()))
+                    .collect()
+            }
+        }
+    }
+
+}
+
+/// Flattens nested unions, removes ∅ and duplicates and unwraps unions of a single term
+fn aci_union<Letter : AutLetter>(terms : impl Iterator<Item=TermBRE<Letter>>) -> TermBRE<Letter> {
+    let mut flattened : BTreeSet<TermBRE<Letter>> = BTreeSet::new();
+    for term in terms {
+        match term {
+            TermBRE::Empty => {},
+            TermBRE::Union(sub_terms) => {
+                flattened.extend(sub_terms.into_iter().filter(|t| *t != TermBRE::Empty));
+            },
+            _ => {
+                flattened.insert(term);
+            }
+        }
+    }
+    match flattened.len() {
+        0 => TermBRE::Empty,
+        1 => flattened.into_iter().next().unwrap(),
+        _ => TermBRE::Union(flattened)
+    }
+}
+
+
+impl<Letter : AutLetter> ExpBRE<Letter> {
+
+    /// Returns true if the expression expresses *word*, by deriving the term w.r.t. each of its letters
+    /// and checking whether the last derivative expresses 𝜀
+    pub fn matches(&self, word : &[Letter]) -> bool {
+        let mut term = self.term.clone();
+        for letter in word {
+            if term == TermBRE::Empty {
+                return false;
+            }
+            term = term.derivative(letter);
+        }
+        term.expresses_epsilon()
+    }
+
+    /// Returns a DFA whose states are labelled by the iterated derivatives of the term (see "bre_to_dfa_by_derivatives")
+    pub fn to_dfa_by_derivatives(&self) -> DerivativesDFA<Letter> {
+        bre_to_dfa_by_derivatives(self)
+    }
+
+    /// Returns a NFA whose states are labelled by the iterated partial derivatives of the term (see "bre_to_nfa_by_partial_derivatives")
+    pub fn to_nfa_by_partial_derivatives(&self) -> PartialDerivativesNFA<Letter> {
+        bre_to_nfa_by_partial_derivatives(self)
+    }
+
+}
@@ -17,4 +17,12 @@ limitations under the License.
 mod build;
 mod translate;
 mod tostring;
-mod letter;
\ No newline at end of file
+mod letter;
+mod characterize;
+mod enumerate;
+mod derivative;
+mod simplify;
+mod access;
+mod transform;
+#[cfg(feature = "serde")]
+mod serialize;
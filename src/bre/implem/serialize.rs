@@ -0,0 +1,75 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "term":term}
+/// where the term is serialized as per its derived implementation e.g. {"Concat":[{"Literal":"a"},"Epsilon"]}
+#[derive(Serialize, Deserialize)]
+struct BRELayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    term : TermBRE<Letter>
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for ExpBRE<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BRELayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            term : self.term.clone()
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for ExpBRE<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = BRELayout::<Letter>::deserialize(deserializer)?;
+        ExpBRE::from_raw(layout.alphabet.into_iter().collect(),layout.term).map_err(D::Error::custom)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::parsers::bre_parser::parse_bre;
+
+    #[test]
+    fn bre_serialization_test() {
+        let alphabet = hashset!{'a','b'};
+        let bre = parse_bre("ab*",&alphabet).unwrap();
+        let json = serde_json::to_string(&bre).unwrap();
+        assert_eq!(json, r#"{"alphabet":["a","b"],"term":{"Concat":[{"Literal":"a"},{"Kleene":{"Literal":"b"}}]}}"#);
+        let got : ExpBRE<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.alphabet, bre.alphabet);
+        assert_eq!(got.term, bre.term);
+        // a letter which is not in the alphabet
+        let invalid = r#"{"alphabet":["a"],"term":{"Literal":"b"}}"#;
+        assert!(serde_json::from_str::<ExpBRE<char>>(invalid).is_err());
+        // an unknown operator
+        let invalid = r#"{"alphabet":["a"],"term":{"Repeat":[{"Literal":"a"},1,2]}}"#;
+        assert!(serde_json::from_str::<ExpBRE<char>>(invalid).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::bre::algos::simplification::simplify_term_bre;
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::traits::letter::AutLetter;
+
+
+impl<Letter : AutLetter> TermBRE<Letter> {
+
+    /// Returns an equivalent term which is not larger, obtained via Kleene algebra rewritings (see "simplify_term_bre")
+    pub fn simplify(&self) -> Self {
+        simplify_term_bre(self)
+    }
+
+}
+
+impl<Letter : AutLetter> ExpBRE<Letter> {
+
+    /// Returns an equivalent expression over the same alphabet whose term is simplified (see "TermBRE::simplify")
+    pub fn simplify(&self) -> Self {
+        ExpBRE{alphabet:self.alphabet.clone(),term:self.term.simplify()}
+    }
+
+}
@@ -73,6 +73,8 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermBRE<Letter> where
             TermBRE::Empty => {printer.get_empty_symbol(use_html).to_string()},
             TermBRE::Epsilon => {printer.get_epsilon_symbol(use_html).to_string()},
             TermBRE::Literal(letter) => {printer.get_letter_string_repr(letter)},
+            TermBRE::Concat(sub_terms) if sub_terms.is_empty() => {printer.get_epsilon_symbol(use_html).to_string()},
+            TermBRE::Union(sub_terms) if sub_terms.is_empty() => {printer.get_empty_symbol(use_html).to_string()},
             TermBRE::Concat(sub_terms) => {
                 let sub_strs_atoms : Vec<(String,bool)> = sub_terms.iter()
                     .map(|t|
@@ -89,9 +91,14 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermBRE<Letter> where
                             }
                     ).collect()
                 } else {
-                    sub_strs_atoms.into_iter()
-                        .map(|(repr,_)|
-                            repr
+                    // without separator, only alternations need parentheses
+                    sub_strs_atoms.into_iter().zip(sub_terms.iter())
+                        .map(|((repr,is_atomic),t)|
+                            if !is_atomic && matches!(t, TermBRE::Union(_)) {
+                                format!("({})",repr)
+                            } else {
+                                repr
+                            }
                         ).collect()
                 };
                 sub_strs.join(printer.get_concatenation_separator(use_html))
@@ -0,0 +1,131 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::transform::AutTransformable;
+use crate::traits::translate::AutTranslatable;
+
+
+impl<Letter : AutLetter> TermBRE<Letter> {
+
+    /// Returns a term expressing the mirror images of the words expressed by *self*
+    pub fn reverse(self) -> Self {
+        match self {
+            TermBRE::Union(sub_terms) => {
+                TermBRE::Union(sub_terms.into_iter().map(|t| t.reverse()).collect())
+            },
+            TermBRE::Concat(sub_terms) => {
+                TermBRE::Concat(sub_terms.into_iter().rev().map(|t| t.reverse()).collect())
+            },
+            TermBRE::Kleene(sub_term) => {
+                TermBRE::Kleene(Box::new(sub_term.reverse()))
+            },
+            _ => self
+        }
+    }
+
+}
+
+
+impl<Letter: AutLetter> AutTransformable<Letter> for ExpBRE<Letter> {
+
+    // an expression has no states so completing it doesn't change it
+    fn complete(self) -> Self {
+        self
+    }
+
+    fn negate(self) -> Self {
+        self.to_dfa().negate().to_bre()
+    }
+
+    fn reverse(mut self) -> Self {
+        self.term = self.term.reverse();
+        self
+    }
+
+    fn minimize(self) -> Self {
+        self.to_dfa().minimize().to_bre()
+    }
+
+    fn intersect(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfa().intersect(other.to_nfa()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_bre())}
+        }
+    }
+
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfa().difference(other.to_nfa()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_bre())}
+        }
+    }
+
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_dfa().symmetric_difference(other.to_dfa()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_bre())}
+        }
+    }
+
+    fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfa().interleave(other.to_nfa()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_bre())}
+        }
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::random::params::RandomTermParameters;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::transform::AutTransformable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::for_each_random_terms;
+
+    #[test]
+    fn bre_transformations_test() {
+        let params = RandomTermParameters::new(hashset!{'a','b'},4,12);
+        for_each_random_terms(7,30,&params,|[term1,term2]| {
+            let bre1 = ExpBRE::from_raw(hashset!{'a','b'},term1).unwrap();
+            let bre2 = ExpBRE::from_raw(hashset!{'a','b'},term2).unwrap();
+            // ***
+            assert!(bre1.clone().reverse().to_dfa().equals(&bre1.to_dfa().reverse()));
+            assert_eq!(bre1.clone().reverse().reverse().term, bre1.term);
+            assert!(bre1.clone().negate().to_dfa().equals(&bre1.to_dfa().negate()));
+            assert!(bre1.clone().minimize().equals(&bre1));
+            assert!(bre1.clone().complete().equals(&bre1));
+            // ***
+            let intersection = bre1.clone().intersect(bre2.clone()).unwrap();
+            assert!(intersection.to_dfa().equals(&bre1.to_dfa().intersect(bre2.to_dfa()).unwrap()));
+            let difference = bre1.clone().difference(bre2.clone()).unwrap();
+            assert!(difference.to_dfa().equals(&bre1.to_dfa().difference(bre2.to_dfa()).unwrap()));
+            let symmetric_difference = bre1.clone().symmetric_difference(bre2.clone()).unwrap();
+            assert!(symmetric_difference.to_dfa().equals(&bre1.to_dfa().symmetric_difference(bre2.to_dfa()).unwrap()));
+        });
+    }
+}
@@ -14,32 +14,42 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::HashSet;
 use maplit::hashmap;
 
+use crate::bre::algos::glushkov::{bre_to_position_automaton, PositionAutomaton};
+use crate::bre::algos::thompson::term_bre_to_nfait_by_thompson;
 use crate::bre::bre::ExpBRE;
-use crate::bre::term::TermBRE;
 use crate::dfa::dfa::AutDFA;
 use crate::gnfa::gnfa::AutGNFA;
 use crate::nfa::nfa::AutNFA;
 use crate::nfait::nfait::AutNFAIT;
 
 use crate::traits::letter::AutLetter;
-use crate::traits::build::AutBuildable;
 use crate::traits::translate::AutTranslatable;
 
 
+impl<Letter : AutLetter> ExpBRE<Letter> {
+
+    /// Returns the Glushkov automaton of the expression, with one state per occurrence of a letter (see "bre_to_position_automaton")
+    pub fn to_position_automaton(&self) -> PositionAutomaton<Letter> {
+        bre_to_position_automaton(self)
+    }
+
+}
+
 impl<Letter : AutLetter> AutTranslatable<Letter> for ExpBRE<Letter> {
     fn to_dfa(&self) -> AutDFA<Letter> {
         self.to_nfa().to_dfa()
     }
 
+    // Antimirov
     fn to_nfa(&self) -> AutNFA<Letter> {
-        term_bre_to_nfa(&self.term,&self.alphabet)
+        self.to_nfa_by_partial_derivatives().nfa
     }
 
+    // Thompson
     fn to_nfait(&self) -> AutNFAIT<Letter> {
-        self.to_nfa().to_nfait()
+        term_bre_to_nfait_by_thompson(&self.term,&self.alphabet)
     }
 
     fn to_gnfa(&self) -> AutGNFA<Letter> {
@@ -54,30 +64,3 @@ impl<Letter : AutLetter> AutTranslatable<Letter> for ExpBRE<Letter> {
         self.clone()
     }
 }
-
-
-fn term_bre_to_nfa<Letter:AutLetter>(operation : &TermBRE<Letter>, alphabet: &HashSet<Letter>) -> AutNFA<Letter> {
-    match operation {
-        TermBRE::Union(sub_terms) => {
-            sub_terms.iter()
-                .fold(AutNFA::new_accepts_nothing(alphabet.clone()),
-                      |acc, x|
-                          acc.unite(term_bre_to_nfa(x,alphabet)).unwrap()
-            )
-        },
-        TermBRE::Concat(sub_terms) => {
-            sub_terms.iter()
-                .fold(AutNFA::new_empty_word(alphabet.clone()),
-                      |acc, x|
-                          acc.concatenate(term_bre_to_nfa(x,alphabet)).unwrap()
-                )
-        },
-        TermBRE::Kleene(sub_term) => {
-            term_bre_to_nfa(sub_term,alphabet).kleene()
-        },
-        TermBRE::Literal(letter) => AutNFA::new_matching(alphabet.clone(), &[*letter]),
-        TermBRE::Epsilon => AutNFA::new_empty_word(alphabet.clone()),
-        TermBRE::Empty => AutNFA::new_accepts_nothing(alphabet.clone())
-    }
-}
-
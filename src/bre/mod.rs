@@ -16,5 +16,6 @@ limitations under the License.
 
 pub mod term;
 pub mod bre;
+pub mod algos;
 mod implem;
 
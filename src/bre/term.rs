@@ -21,6 +21,7 @@ use crate::traits::letter::AutLetter;
 
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TermBRE<Letter: AutLetter> {
     Empty,
     Epsilon,
@@ -57,6 +58,22 @@ impl<Letter: AutLetter> TermBRE<Letter> {
         alphabet
     }
 
+    /// Returns the number of nodes in the syntax tree of the term
+    pub fn size(&self) -> usize {
+        match self {
+            TermBRE::Union(sub_terms) => {
+                1 + sub_terms.iter().map(|t| t.size()).sum::<usize>()
+            },
+            TermBRE::Concat(sub_terms) => {
+                1 + sub_terms.iter().map(|t| t.size()).sum::<usize>()
+            },
+            TermBRE::Kleene(sub_term) => {
+                1 + sub_term.size()
+            },
+            _ => 1
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         match self {
             TermBRE::Empty => true,
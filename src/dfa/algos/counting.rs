@@ -0,0 +1,311 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use num::{BigInt, BigUint, One, Zero};
+
+use crate::dfa::algos::minimization::algo::{minimize_dfa, MinimizationAlgorithm};
+use crate::dfa::dfa::AutDFA;
+use crate::traits::letter::AutLetter;
+
+
+/// The generating function "sum over n of c_n z^n" where c_n is the number of accepted words of length n,
+/// as the quotient of two polynomials which are given by their coefficients in increasing degree order.
+/// The quotient is not necessarily in lowest terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RationalGeneratingFunction {
+    pub numerator : Vec<BigInt>,
+    pub denominator : Vec<BigInt>
+}
+
+/// How the number c_n of accepted words of length n grows with n
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageGrowth {
+    /// c_n is zero for n large enough
+    Finite,
+    /// c_n is in O(n^k) and not in O(n^(k-1)), k being given
+    Polynomial(usize),
+    /// c_n is not bounded by any polynomial
+    Exponential
+}
+
+
+/// Calls *on_each_length* for each length k from 0 to *length* with, for each state, the number of words of length k leading to that state from the initial state
+fn count_words_per_state<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                             length : usize,
+                                             on_each_length : &mut dyn FnMut(&[BigUint])) {
+    let mut counts = vec![BigUint::zero();dfa.transitions.len()];
+    counts[dfa.initial] = BigUint::one();
+    on_each_length(&counts);
+    for _ in 0..length {
+        let mut next = vec![BigUint::zero();dfa.transitions.len()];
+        for (orig,transitions) in dfa.transitions.iter().enumerate() {
+            if counts[orig].is_zero() {
+                continue;
+            }
+            for targ in transitions.values() {
+                next[*targ] += &counts[orig];
+            }
+        }
+        counts = next;
+        on_each_length(&counts);
+    }
+}
+
+fn count_accepted<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                      counts : &[BigUint]) -> BigUint {
+    dfa.finals.iter().map(|state| &counts[*state]).sum()
+}
+
+/// Returns the number of words of length *length* accepted by *dfa*
+pub fn count_accepted_words_of_length<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                                          length : usize) -> BigUint {
+    let mut last = BigUint::zero();
+    count_words_per_state(dfa,length,&mut |counts| last = count_accepted(dfa,counts));
+    last
+}
+
+/// Returns the number of words of length at most *length* accepted by *dfa*
+pub fn count_accepted_words_up_to_length<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                                             length : usize) -> BigUint {
+    let mut total = BigUint::zero();
+    count_words_per_state(dfa,length,&mut |counts| total += count_accepted(dfa,counts));
+    total
+}
+
+
+/// Returns the generating function of the language of *dfa*.
+///
+/// With A the matrix such that A[i][j] is the number of letters labelling transitions from i to j in the minimal DFA,
+/// the denominator is det(I - zA) i.e. the reversed characteristic polynomial of A, which is computed with the Faddeev-LeVerrier algorithm.
+/// The numerator is the product of the denominator with the series of the counts, truncated below the number of states.
+pub fn get_generating_function<Letter : AutLetter>(dfa : &AutDFA<Letter>) -> RationalGeneratingFunction {
+    let (minimal,_) = minimize_dfa(dfa,MinimizationAlgorithm::Hopcroft);
+    let num_states = minimal.transitions.len();
+    let mut matrix = vec![vec![BigInt::zero();num_states];num_states];
+    for (orig,transitions) in minimal.transitions.iter().enumerate() {
+        for targ in transitions.values() {
+            matrix[orig][*targ] += 1;
+        }
+    }
+    // ***
+    // Faddeev-LeVerrier : the characteristic polynomial is "sum over k of c_k x^k" with c_n = 1
+    // and the coefficient of z^k in det(I - zA) is c_(n-k)
+    let mut denominator = vec![BigInt::one()];
+    let mut m = vec![vec![BigInt::zero();num_states];num_states];
+    let mut previous_coefficient = BigInt::one();
+    for k in 1..=num_states {
+        let mut next_m = multiply(&matrix,&m);
+        for (i,row) in next_m.iter_mut().enumerate() {
+            row[i] += &previous_coefficient;
+        }
+        let am = multiply(&matrix,&next_m);
+        let trace : BigInt = (0..num_states).map(|i| &am[i][i]).sum();
+        // the division is exact
+        previous_coefficient = -trace / BigInt::from(k);
+        denominator.push(previous_coefficient.clone());
+        m = next_m;
+    }
+    // ***
+    let mut counts = vec![];
+    count_words_per_state(&minimal,num_states.saturating_sub(1),&mut |c| counts.push(BigInt::from(count_accepted(&minimal,c))));
+    let numerator = (0..num_states)
+        .map(|k| (0..=k).map(|i| &denominator[i] * &counts[k - i]).sum())
+        .collect();
+    RationalGeneratingFunction{numerator:trim_polynomial(numerator),denominator:trim_polynomial(denominator)}
+}
+
+fn multiply(left : &[Vec<BigInt>], right : &[Vec<BigInt>]) -> Vec<Vec<BigInt>> {
+    let size = left.len();
+    (0..size).map(|i|
+        (0..size).map(|j|
+            (0..size).filter(|k| !left[i][*k].is_zero()).map(|k| &left[i][k] * &right[k][j]).sum()
+        ).collect()
+    ).collect()
+}
+
+/// removes the zero coefficients of highest degree
+fn trim_polynomial(mut coefficients : Vec<BigInt>) -> Vec<BigInt> {
+    while coefficients.len() > 1 && coefficients.last().unwrap().is_zero() {
+        coefficients.pop();
+    }
+    if coefficients.is_empty() {
+        coefficients.push(BigInt::zero());
+    }
+    coefficients
+}
+
+
+/// Returns the growth of the number of words of length n accepted by *dfa*.
+///
+/// In the minimal DFA, which is trimmed, the growth is exponential if a strongly connected component has more transitions than states
+/// i.e. contains two distinct cycles. Otherwise, the degree of the polynomial growth is one less than
+/// the maximal number of cyclic components which are traversed by a path.
+pub fn get_language_growth<Letter : AutLetter>(dfa : &AutDFA<Letter>) -> LanguageGrowth {
+    let (minimal,_) = minimize_dfa(dfa,MinimizationAlgorithm::Hopcroft);
+    let successors : Vec<Vec<usize>> = minimal.transitions.iter()
+        .map(|transitions| transitions.values().cloned().collect())
+        .collect();
+    let (component_of,num_components) = get_strongly_connected_components(&successors);
+    // ***
+    let mut sizes = vec![0;num_components];
+    let mut internal_transitions = vec![0;num_components];
+    for (orig,targets) in successors.iter().enumerate() {
+        sizes[component_of[orig]] += 1;
+        for targ in targets {
+            if component_of[*targ] == component_of[orig] {
+                internal_transitions[component_of[orig]] += 1;
+            }
+        }
+    }
+    if (0..num_components).any(|c| internal_transitions[c] > sizes[c]) {
+        return LanguageGrowth::Exponential;
+    }
+    // ***
+    // components are numbered in a topological order so successors have greater numbers
+    let mut cyclic_on_paths = vec![0;num_components];
+    for component in (0..num_components).rev() {
+        let mut best_successor = 0;
+        for (orig,targets) in successors.iter().enumerate() {
+            if component_of[orig] == component {
+                for targ in targets {
+                    if component_of[*targ] != component {
+                        best_successor = best_successor.max(cyclic_on_paths[component_of[*targ]]);
+                    }
+                }
+            }
+        }
+        let is_cyclic = if internal_transitions[component] > 0 {1} else {0};
+        cyclic_on_paths[component] = best_successor + is_cyclic;
+    }
+    match cyclic_on_paths[component_of[minimal.initial]] {
+        0 => LanguageGrowth::Finite,
+        num_cyclic => LanguageGrowth::Polynomial(num_cyclic - 1)
+    }
+}
+
+/// Kosaraju's algorithm.
+/// Returns the component of each state, components being numbered in a topological order, and the number of components.
+fn get_strongly_connected_components(successors : &[Vec<usize>]) -> (Vec<usize>,usize) {
+    let num_states = successors.len();
+    let mut predecessors = vec![vec![];num_states];
+    for (orig,targets) in successors.iter().enumerate() {
+        for targ in targets {
+            predecessors[*targ].push(orig);
+        }
+    }
+    // first pass : states ordered by finishing time of an iterative depth first search
+    let mut visited = vec![false;num_states];
+    let mut finish_order = vec![];
+    for root in 0..num_states {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root,0)];
+        while let Some((state,next_child)) = stack.last_mut() {
+            if let Some(child) = successors[*state].get(*next_child) {
+                *next_child += 1;
+                if !visited[*child] {
+                    visited[*child] = true;
+                    stack.push((*child,0));
+                }
+            } else {
+                finish_order.push(*state);
+                stack.pop();
+            }
+        }
+    }
+    // second pass : on the transposed graph by decreasing finishing time
+    let mut component_of = vec![usize::MAX;num_states];
+    let mut num_components = 0;
+    for root in finish_order.into_iter().rev() {
+        if component_of[root] != usize::MAX {
+            continue;
+        }
+        component_of[root] = num_components;
+        let mut stack = vec![root];
+        while let Some(state) = stack.pop() {
+            for pred in &predecessors[state] {
+                if component_of[*pred] == usize::MAX {
+                    component_of[*pred] = num_components;
+                    stack.push(*pred);
+                }
+            }
+        }
+        num_components += 1;
+    }
+    (component_of,num_components)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use num::{BigInt, BigUint};
+    use crate::dfa::algos::counting::*;
+    use crate::dfa::dfa::AutDFA;
+    use crate::tests_utils::get_even_a;
+
+    /// a DFA accepting a*b*
+    fn get_a_star_b_star() -> AutDFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, usize>> = vec![
+            hashmap!{'a' => 0, 'b' => 1},
+            hashmap!{'b' => 1}
+        ];
+        AutDFA::<char>::from_raw(alphabet, 0, hashset!{0,1}, transitions).unwrap()
+    }
+
+    #[test]
+    fn counting_test() {
+        let dfa = get_even_a(hashset!{'a','b'});
+        // 2^(n-1) words of length n for n>0
+        assert_eq!(count_accepted_words_of_length(&dfa,0), BigUint::from(1_u32));
+        assert_eq!(count_accepted_words_of_length(&dfa,10), BigUint::from(512_u32));
+        assert_eq!(count_accepted_words_up_to_length(&dfa,3), BigUint::from(8_u32));
+        assert_eq!(count_accepted_words_of_length(&dfa,200), BigUint::from(2_u32).pow(199));
+        // n+1 words of length n
+        assert_eq!(count_accepted_words_of_length(&get_a_star_b_star(),7), BigUint::from(8_u32));
+    }
+
+    #[test]
+    fn generating_function_test() {
+        let to_big = |v : Vec<i32>| v.into_iter().map(BigInt::from).collect::<Vec<BigInt>>();
+        // (1 - z)/(1 - 2z) = 1 + z + 2z^2 + ... with det(I - zA) = (1-z)(1-z) - z^2 = 1 - 2z
+        let gf = get_generating_function(&get_even_a(hashset!{'a','b'}));
+        assert_eq!(gf.denominator, to_big(vec![1,-2]));
+        assert_eq!(gf.numerator, to_big(vec![1,-1]));
+        // 1/(1-z)^2
+        let gf = get_generating_function(&get_a_star_b_star());
+        assert_eq!(gf.denominator, to_big(vec![1,-2,1]));
+        assert_eq!(gf.numerator, to_big(vec![1]));
+    }
+
+    #[test]
+    fn growth_test() {
+        assert_eq!(get_language_growth(&get_even_a(hashset!{'a','b'})), LanguageGrowth::Exponential);
+        assert_eq!(get_language_growth(&get_a_star_b_star()), LanguageGrowth::Polynomial(1));
+        let mut only_a_star = get_a_star_b_star();
+        only_a_star.finals.remove(&1);
+        assert_eq!(get_language_growth(&only_a_star), LanguageGrowth::Polynomial(0));
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let finite = AutDFA::<char>::from_raw(alphabet, 0, hashset!{1}, vec![hashmap!{'a' => 1, 'b' => 1},hashmap!{}]).unwrap();
+        assert_eq!(get_language_growth(&finite), LanguageGrowth::Finite);
+    }
+}
@@ -0,0 +1,192 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::dfa::dfa::AutDFA;
+use crate::traits::access::AutAccessible;
+use crate::traits::letter::AutLetter;
+
+
+/// Iterates over the words accepted by a DFA in shortlex order i.e. by increasing length and then in lexicographic order.
+///
+/// Words of each length are enumerated via a depth first search which only follows transitions
+/// towards states from which a final state can be reached with exactly the number of remaining letters.
+/// The iteration stops as soon as no accessible state can reach a final state with that many letters,
+/// which always happens for finite languages.
+pub struct ShortlexWordsIterator<Letter : AutLetter> {
+    letters : Vec<Letter>,
+    // "delta[state][letter_index]" is the target of the transition if there is any
+    delta : Vec<Vec<Option<usize>>>,
+    initial : usize,
+    accessible : Vec<bool>,
+    // "live[k][state]" is true if a final state can be reached from state with a word of length k
+    live : Vec<Vec<bool>>,
+    length : usize,
+    // stack of the visited states along with the index of the next letter to try
+    stack : Vec<(usize,usize)>,
+    word : Vec<Letter>,
+    max_length : Option<usize>,
+    max_count : Option<usize>,
+    count : usize,
+    finished : bool
+}
+
+impl<Letter : AutLetter> ShortlexWordsIterator<Letter> {
+
+    pub fn new(dfa : &AutDFA<Letter>) -> Self {
+        let mut letters : Vec<Letter> = dfa.alphabet.iter().cloned().collect();
+        letters.sort();
+        let delta = dfa.transitions.iter()
+            .map(|map| letters.iter().map(|letter| map.get(letter).cloned()).collect())
+            .collect();
+        let mut accessible = vec![false;dfa.transitions.len()];
+        for state in dfa.get_all_accessible_states() {
+            accessible[state] = true;
+        }
+        let live = vec![(0..dfa.transitions.len()).map(|state| accessible[state] && dfa.finals.contains(&state)).collect()];
+        let mut iterator = ShortlexWordsIterator{
+            letters,delta,initial:dfa.initial,accessible,live,
+            length:0,stack:vec![],word:vec![],
+            max_length:None,max_count:None,count:0,finished:false
+        };
+        iterator.start_length();
+        iterator
+    }
+
+    /// Only words of length at most *max_length* are enumerated
+    pub fn with_max_length(mut self, max_length : usize) -> Self {
+        self.max_length = Some(max_length);
+        if self.length > max_length {
+            self.finished = true;
+        }
+        self
+    }
+
+    /// At most *max_count* words are enumerated
+    pub fn with_max_count(mut self, max_count : usize) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    fn start_length(&mut self) {
+        while self.live.len() <= self.length {
+            let previous = self.live.last().unwrap();
+            let next : Vec<bool> = self.delta.iter().enumerate()
+                .map(|(state,targets)| self.accessible[state]
+                    && targets.iter().any(|targ| targ.is_some_and(|t| previous[t])))
+                .collect();
+            self.live.push(next);
+        }
+        let live = &self.live[self.length];
+        if !live.iter().any(|x| *x) {
+            self.finished = true;
+        } else if live[self.initial] {
+            self.stack.push((self.initial,0));
+        }
+    }
+}
+
+impl<Letter : AutLetter> Iterator for ShortlexWordsIterator<Letter> {
+    type Item = Vec<Letter>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.finished {
+                return None;
+            }
+            if self.max_count.is_some_and(|max| self.count >= max) {
+                self.finished = true;
+                return None;
+            }
+            // ***
+            match self.stack.last_mut() {
+                None => {
+                    // all the words of the current length have been enumerated
+                    self.length += 1;
+                    if self.max_length.is_some_and(|max| self.length > max) {
+                        self.finished = true;
+                    } else {
+                        self.start_length();
+                    }
+                },
+                Some((state,next_letter)) => {
+                    let remaining = self.length - self.word.len();
+                    if remaining == 0 {
+                        let found = self.word.clone();
+                        self.stack.pop();
+                        self.word.pop();
+                        self.count += 1;
+                        return Some(found);
+                    }
+                    let state = *state;
+                    let next_child = (*next_letter..self.letters.len())
+                        .find(|letter_index| self.delta[state][*letter_index]
+                            .is_some_and(|targ| self.live[remaining - 1][targ]));
+                    match next_child {
+                        None => {
+                            self.stack.pop();
+                            self.word.pop();
+                        },
+                        Some(letter_index) => {
+                            *next_letter = letter_index + 1;
+                            self.word.push(self.letters[letter_index]);
+                            self.stack.push((self.delta[state][letter_index].unwrap(),0));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::dfa::algos::enumeration::ShortlexWordsIterator;
+    use crate::dfa::dfa::AutDFA;
+    use crate::tests_utils::get_even_a;
+
+    #[test]
+    fn shortlex_enumeration_test() {
+        let words : Vec<String> = ShortlexWordsIterator::new(&get_even_a(hashset!{'a','b'}))
+            .with_max_length(3)
+            .map(|w| w.into_iter().collect())
+            .collect();
+        assert_eq!(words, vec!["","b","aa","bb","aab","aba","baa","bbb"]);
+        let count = ShortlexWordsIterator::new(&get_even_a(hashset!{'a','b'})).with_max_count(5).count();
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn finite_language_enumeration_test() {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        // accepts "ab" and "b" and has an inaccessible loop on a final state
+        let transitions: Vec<HashMap<char, usize>> = vec![
+            hashmap!{'a' => 1, 'b' => 2},
+            hashmap!{'b' => 2},
+            hashmap!{},
+            hashmap!{'a' => 3}
+        ];
+        let dfa = AutDFA::<char>::from_raw(alphabet.clone(), 0, hashset!{2,3}, transitions).unwrap();
+        let words : Vec<Vec<char>> = ShortlexWordsIterator::new(&dfa).collect();
+        assert_eq!(words, vec![vec!['b'],vec!['a','b']]);
+        let empty = AutDFA::<char>::new_void_object(alphabet);
+        assert_eq!(ShortlexWordsIterator::new(&empty).next(), None);
+    }
+}
@@ -0,0 +1,195 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::VecDeque;
+
+use crate::dfa::dfa::AutDFA;
+use crate::traits::letter::AutLetter;
+
+
+/// Union-find over the states of the disjoint union of the two DFA.
+struct StatesPartition {
+    parent : Vec<usize>,
+    rank : Vec<usize>
+}
+
+impl StatesPartition {
+
+    fn new(num_states : usize) -> Self {
+        StatesPartition{parent:(0..num_states).collect(),rank:vec![0;num_states]}
+    }
+
+    fn find(&mut self, state : usize) -> usize {
+        let mut root = state;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // path compression
+        let mut current = state;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merges the classes of the two states and returns false if they were already the same
+    fn union(&mut self, state1 : usize, state2 : usize) -> bool {
+        let root1 = self.find(state1);
+        let root2 = self.find(state2);
+        if root1 == root2 {
+            return false;
+        }
+        if self.rank[root1] < self.rank[root2] {
+            self.parent[root1] = root2;
+        } else {
+            self.parent[root2] = root1;
+            if self.rank[root1] == self.rank[root2] {
+                self.rank[root1] += 1;
+            }
+        }
+        true
+    }
+}
+
+
+/// A pair of states reached with the same word, along with the pair and letter from which it is reached.
+struct ExploredPair<Letter : AutLetter> {
+    left : usize,
+    right : usize,
+    origin : Option<(usize,Letter)>
+}
+
+
+/// Hopcroft and Karp's equivalence check.
+/// Pairs of states reached with the same words are merged in a union-find structure
+/// and the pairs whose classes are merged are explored in breadth first order.
+/// The languages differ if and only if a final state is merged with a non-final state.
+///
+/// Missing transitions go to implicit sink states so the DFA need not be complete,
+/// and the letters of both alphabets are considered so the alphabets need not be the same.
+/// The time complexity is almost linear in the number of states times the number of letters.
+///
+/// Returns None if both DFA accept the same language and otherwise a word accepted by exactly one of them.
+pub fn hopcroft_karp_distinguishing_word<Letter : AutLetter>(left : &AutDFA<Letter>,
+                                                             right : &AutDFA<Letter>) -> Option<Vec<Letter>> {
+    let mut letters : Vec<Letter> = left.alphabet.union(&right.alphabet).cloned().collect();
+    letters.sort();
+    // the states of right are shifted after the states of left and the sink of left
+    let left_sink = left.transitions.len();
+    let shift = left_sink + 1;
+    let right_sink = shift + right.transitions.len();
+    let target = |state : usize, letter : &Letter| -> usize {
+        if state < left_sink {
+            left.transitions[state].get(letter).cloned().unwrap_or(left_sink)
+        } else if state == left_sink {
+            left_sink
+        } else if state < right_sink {
+            right.transitions[state - shift].get(letter).map(|targ| targ + shift).unwrap_or(right_sink)
+        } else {
+            right_sink
+        }
+    };
+    let is_final = |state : usize| -> bool {
+        if state < left_sink {
+            left.finals.contains(&state)
+        } else if state > left_sink && state < right_sink {
+            right.finals.contains(&(state - shift))
+        } else {
+            false
+        }
+    };
+    // ***
+    let mut partition = StatesPartition::new(right_sink + 1);
+    let mut pairs = vec![ExploredPair{left:left.initial,right:right.initial + shift,origin:None}];
+    partition.union(left.initial, right.initial + shift);
+    // there are at most as many explored pairs as there are unions i.e. fewer than the total number of states
+    let mut queue = VecDeque::from([0]);
+    while let Some(pair_id) = queue.pop_front() {
+        let (p,q) = (pairs[pair_id].left,pairs[pair_id].right);
+        if is_final(p) != is_final(q) {
+            return Some(get_word_of_pair(&pairs,pair_id));
+        }
+        for letter in &letters {
+            let p_targ = target(p,letter);
+            let q_targ = target(q,letter);
+            if partition.union(p_targ,q_targ) {
+                queue.push_back(pairs.len());
+                pairs.push(ExploredPair{left:p_targ,right:q_targ,origin:Some((pair_id,*letter))});
+            }
+        }
+    }
+    None
+}
+
+fn get_word_of_pair<Letter : AutLetter>(pairs : &[ExploredPair<Letter>],
+                                        pair_id : usize) -> Vec<Letter> {
+    let mut word = vec![];
+    let mut current = pair_id;
+    while let Some((previous,letter)) = pairs[current].origin {
+        word.push(letter);
+        current = previous;
+    }
+    word.reverse();
+    word
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::dfa::algos::equivalence::hopcroft_karp_distinguishing_word;
+    use crate::dfa::dfa::AutDFA;
+    use crate::traits::run::AutRunnable;
+    use crate::tests_utils::get_even_a;
+
+    /// the language of "get_even_a" with states 0 and 2 (resp. 1 and 3) being equivalent
+    fn get_even_a_with_four_states() -> AutDFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, usize>> = vec![
+            hashmap!{'a' => 1, 'b' => 2},
+            hashmap!{'a' => 2, 'b' => 3},
+            hashmap!{'a' => 3, 'b' => 0},
+            hashmap!{'a' => 0, 'b' => 1}
+        ];
+        AutDFA::<char>::from_raw(alphabet, 0, hashset!{0,2}, transitions).unwrap()
+    }
+
+    #[test]
+    fn equivalent_dfa_test() {
+        let dfa1 = get_even_a_with_four_states();
+        let dfa2 = get_even_a(hashset!{'a','b'});
+        assert_eq!(hopcroft_karp_distinguishing_word(&dfa1,&dfa2), None);
+        assert_eq!(hopcroft_karp_distinguishing_word(&dfa2,&dfa1), None);
+    }
+
+    #[test]
+    fn distinguishing_word_test() {
+        let dfa1 = get_even_a_with_four_states();
+        let mut dfa2 = get_even_a(hashset!{'a','b'});
+        // the second DFA now rejects all the words containing "bb"
+        dfa2.transitions[0].remove(&'b');
+        dfa2.transitions.push(hashmap!{'a' => 1});
+        dfa2.transitions[0].insert('b',2);
+        dfa2.finals.insert(2);
+        let word = hopcroft_karp_distinguishing_word(&dfa1,&dfa2).unwrap();
+        assert_ne!(dfa1.runs_trace(&word).unwrap(), dfa2.runs_trace(&word).unwrap());
+    }
+}
@@ -0,0 +1,221 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use maplit::hashset;
+
+use crate::dfa::algos::minimization::hopcroft::hopcroft_partition;
+use crate::dfa::algos::minimization::moore::moore_partition;
+use crate::dfa::dfa::AutDFA;
+use crate::traits::access::AutAccessible;
+use crate::traits::letter::AutLetter;
+use crate::traits::transform::AutTransformable;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MinimizationAlgorithm {
+    /// Hopcroft's partition refinement in O(n log n)
+    #[default]
+    Hopcroft,
+    /// Moore's iterative refinement in O(n^2)
+    Moore,
+    /// Brzozowski's double reversal, which may involve exponential subset constructions
+    Brzozowski
+}
+
+
+/// Returns a minimal DFA accepting the same language as *dfa* and a map associating each state of *dfa* to its equivalence class i.e. to a state of the minimal DFA.
+/// The minimal DFA is trimmed so states which are not accessible or not coaccessible are mapped to None,
+/// except for the initial state which is always mapped to the initial state of the minimal DFA.
+pub fn minimize_dfa<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                        algorithm : MinimizationAlgorithm) -> (AutDFA<Letter>,Vec<Option<usize>>) {
+    match algorithm {
+        MinimizationAlgorithm::Brzozowski => {
+            let minimized = dfa.clone().reverse().reverse();
+            let states_map = map_states_to_equivalent_dfa(dfa,&minimized);
+            (minimized,states_map)
+        },
+        _ => {
+            minimize_dfa_by_partition_refinement(dfa,algorithm)
+        }
+    }
+}
+
+
+fn minimize_dfa_by_partition_refinement<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                                            algorithm : MinimizationAlgorithm) -> (AutDFA<Letter>,Vec<Option<usize>>) {
+    // letters are sorted so that the numbering of the states of the minimal DFA is deterministic
+    let mut letters : Vec<Letter> = dfa.alphabet.iter().cloned().collect();
+    letters.sort();
+    // ***
+    // only accessible states are considered
+    let mut accessible : Vec<usize> = dfa.get_all_accessible_states().into_iter().collect();
+    accessible.sort();
+    let mut compact_ids : HashMap<usize,usize> = HashMap::new();
+    for (compact_id,state) in accessible.iter().enumerate() {
+        compact_ids.insert(*state,compact_id);
+    }
+    // ***
+    // we complete the transition table with a sink state
+    let sink = accessible.len();
+    let mut delta : Vec<Vec<usize>> = vec![];
+    let mut is_final : Vec<bool> = vec![];
+    for state in &accessible {
+        delta.push(letters.iter()
+            .map(|letter| match dfa.transitions[*state].get(letter) {
+                None => sink,
+                Some(targ) => *compact_ids.get(targ).unwrap()
+            }).collect());
+        is_final.push(dfa.finals.contains(state));
+    }
+    delta.push(vec![sink;letters.len()]);
+    is_final.push(false);
+    // ***
+    let block_of = match algorithm {
+        MinimizationAlgorithm::Moore => moore_partition(&delta,&is_final),
+        _ => hopcroft_partition(&delta,&is_final,letters.len())
+    };
+    // ***
+    // the block of the sink contains all the states which are not coaccessible
+    let dead_block = block_of[sink];
+    let initial_block = block_of[*compact_ids.get(&dfa.initial).unwrap()];
+    if initial_block == dead_block {
+        let mut states_map = vec![None;dfa.transitions.len()];
+        states_map[dfa.initial] = Some(0);
+        return (AutDFA::new_void_object(dfa.alphabet.clone()),states_map);
+    }
+    // ***
+    // the states of the minimal DFA are numbered following a breadth first search from the initial block
+    let mut representatives : HashMap<usize,usize> = HashMap::new();
+    for compact_id in (0..sink).rev() {
+        representatives.insert(block_of[compact_id], compact_id);
+    }
+    let mut new_ids : HashMap<usize,usize> = HashMap::new();
+    new_ids.insert(initial_block,0);
+    let mut new_finals = hashset!{};
+    let mut new_transitions = vec![HashMap::new()];
+    let mut queue = VecDeque::from([initial_block]);
+    while let Some(block) = queue.pop_front() {
+        let new_orig = *new_ids.get(&block).unwrap();
+        let representative = *representatives.get(&block).unwrap();
+        if is_final[representative] {
+            new_finals.insert(new_orig);
+        }
+        for (letter_index,letter) in letters.iter().enumerate() {
+            let targ_block = block_of[delta[representative][letter_index]];
+            if targ_block != dead_block {
+                let new_targ = match new_ids.get(&targ_block) {
+                    None => {
+                        let new_targ = new_transitions.len();
+                        new_ids.insert(targ_block,new_targ);
+                        new_transitions.push(HashMap::new());
+                        queue.push_back(targ_block);
+                        new_targ
+                    },
+                    Some(new_targ) => {
+                        *new_targ
+                    }
+                };
+                new_transitions[new_orig].insert(*letter,new_targ);
+            }
+        }
+    }
+    // ***
+    let states_map = (0..dfa.transitions.len())
+        .map(|state| compact_ids.get(&state).and_then(|compact_id| new_ids.get(&block_of[*compact_id]).cloned()))
+        .collect();
+    let minimized = AutDFA::from_raw(dfa.alphabet.clone(),0,new_finals,new_transitions).unwrap();
+    (minimized,states_map)
+}
+
+
+/// Given two DFA accepting the same language where *equivalent* is trimmed,
+/// associates each state of *dfa* with the state of *equivalent* which is reached with the same words (if any).
+fn map_states_to_equivalent_dfa<Letter : AutLetter>(dfa : &AutDFA<Letter>,
+                                                    equivalent : &AutDFA<Letter>) -> Vec<Option<usize>> {
+    let mut states_map = vec![None;dfa.transitions.len()];
+    states_map[dfa.initial] = Some(equivalent.initial);
+    let mut visited : HashSet<usize> = hashset!{dfa.initial};
+    let mut stack = vec![(dfa.initial,equivalent.initial)];
+    while let Some((orig,equiv_orig)) = stack.pop() {
+        for (letter,targ) in &dfa.transitions[orig] {
+            if let Some(equiv_targ) = equivalent.transitions[equiv_orig].get(letter) {
+                states_map[*targ] = Some(*equiv_targ);
+                if visited.insert(*targ) {
+                    stack.push((*targ,*equiv_targ));
+                }
+            }
+        }
+    }
+    states_map
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::dfa::algos::minimization::algo::{minimize_dfa, MinimizationAlgorithm};
+    use crate::dfa::dfa::AutDFA;
+    use crate::traits::run::AutRunnable;
+    use crate::tests_utils::get_all_words;
+
+    /// the language of "get_even_a", with states 0 and 2 (resp. 1 and 3) being equivalent
+    /// and which has an inaccessible state 4 and a dead state 5
+    fn get_example() -> AutDFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, usize>> = vec![
+            hashmap!{'a' => 1, 'b' => 2},
+            hashmap!{'a' => 2, 'b' => 3},
+            hashmap!{'a' => 3, 'b' => 0},
+            hashmap!{'a' => 0, 'b' => 1},
+            hashmap!{'a' => 0, 'b' => 5},
+            hashmap!{'a' => 5, 'b' => 5}
+        ];
+        AutDFA::<char>::from_raw(alphabet, 0, hashset!{0,2}, transitions).unwrap()
+    }
+
+    #[test]
+    fn minimization_algorithms_agree_test() {
+        let dfa = get_example();
+        for algorithm in [MinimizationAlgorithm::Hopcroft, MinimizationAlgorithm::Moore, MinimizationAlgorithm::Brzozowski] {
+            let (minimized,states_map) = minimize_dfa(&dfa,algorithm);
+            assert_eq!(minimized.transitions.len(), 2);
+            for word in get_all_words(6) {
+                assert_eq!(dfa.runs_trace(&word).unwrap(), minimized.runs_trace(&word).unwrap());
+            }
+            assert_eq!(states_map[0], states_map[2]);
+            assert_eq!(states_map[1], states_map[3]);
+            assert_ne!(states_map[0], states_map[1]);
+            assert_eq!(states_map[0], Some(minimized.initial));
+            assert_eq!(states_map[4], None);
+            assert_eq!(states_map[5], None);
+        }
+    }
+
+    #[test]
+    fn minimization_of_empty_language_test() {
+        let mut dfa = get_example();
+        dfa.finals.clear();
+        let (minimized,states_map) = minimize_dfa(&dfa,MinimizationAlgorithm::Hopcroft);
+        assert_eq!(minimized.transitions.len(), 1);
+        assert!(minimized.finals.is_empty());
+        assert_eq!(states_map[0], Some(0));
+        assert_eq!(states_map[1], None);
+    }
+}
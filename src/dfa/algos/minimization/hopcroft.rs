@@ -0,0 +1,124 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+
+/// Hopcroft's partition refinement on a complete transition table
+/// where "delta[state][letter_index]" is the target of the transition.
+/// Returns, for each state, the identifier of its block in the coarsest stable partition.
+pub fn hopcroft_partition(delta : &[Vec<usize>],
+                          is_final : &[bool],
+                          num_letters : usize) -> Vec<usize> {
+    let num_states = delta.len();
+    // inverse[letter][target] gives all the origins of transitions towards target with letter
+    let mut inverse : Vec<Vec<Vec<usize>>> = vec![vec![vec![];num_states];num_letters];
+    for (orig,targets) in delta.iter().enumerate() {
+        for (letter_index,targ) in targets.iter().enumerate() {
+            inverse[letter_index][*targ].push(orig);
+        }
+    }
+    // ***
+    // the states are stored in "elements" so that each block is a contiguous slice
+    let mut elements : Vec<usize> = (0..num_states).filter(|s| is_final[*s]).collect();
+    let num_finals = elements.len();
+    elements.extend((0..num_states).filter(|s| !is_final[*s]));
+    let mut location = vec![0;num_states];
+    for (pos,state) in elements.iter().enumerate() {
+        location[*state] = pos;
+    }
+    // ***
+    let mut blocks : Vec<(usize,usize)> = vec![];
+    let mut block_of = vec![0;num_states];
+    if num_finals == 0 || num_finals == num_states {
+        blocks.push((0,num_states));
+    } else {
+        blocks.push((0,num_finals));
+        blocks.push((num_finals,num_states));
+        for state in &elements[num_finals..] {
+            block_of[*state] = 1;
+        }
+    }
+    let mut marked_count = vec![0;blocks.len()];
+    // ***
+    // the worklist contains splitters i.e. pairs (block,letter)
+    let mut worklist : Vec<(usize,usize)> = vec![];
+    let mut in_worklist : HashSet<(usize,usize)> = HashSet::new();
+    if blocks.len() == 2 {
+        let smallest = if num_finals <= num_states - num_finals {0} else {1};
+        for letter_index in 0..num_letters {
+            worklist.push((smallest,letter_index));
+            in_worklist.insert((smallest,letter_index));
+        }
+    }
+    // ***
+    while let Some((splitter,letter_index)) = worklist.pop() {
+        in_worklist.remove(&(splitter,letter_index));
+        let (start,end) = blocks[splitter];
+        let splitter_states : Vec<usize> = elements[start..end].to_vec();
+        // we mark all the states which can reach the splitter via the letter
+        // marked states are moved at the beginning of their respective blocks
+        let mut touched_blocks = vec![];
+        for targ in splitter_states {
+            for orig in &inverse[letter_index][targ] {
+                let block = block_of[*orig];
+                let first_unmarked = blocks[block].0 + marked_count[block];
+                let orig_pos = location[*orig];
+                let other = elements[first_unmarked];
+                elements.swap(orig_pos, first_unmarked);
+                location[other] = orig_pos;
+                location[*orig] = first_unmarked;
+                marked_count[block] += 1;
+                if marked_count[block] == 1 {
+                    touched_blocks.push(block);
+                }
+            }
+        }
+        // ***
+        for block in touched_blocks {
+            let (start,end) = blocks[block];
+            let num_marked = marked_count[block];
+            marked_count[block] = 0;
+            if num_marked == end - start {
+                continue;
+            }
+            // the old block keeps the largest part and the new block is the smallest part
+            let new_block = blocks.len();
+            if num_marked <= end - start - num_marked {
+                blocks.push((start,start + num_marked));
+                blocks[block].0 = start + num_marked;
+            } else {
+                blocks.push((start + num_marked,end));
+                blocks[block].1 = start + num_marked;
+            }
+            marked_count.push(0);
+            let (new_start,new_end) = blocks[new_block];
+            for state in &elements[new_start..new_end] {
+                block_of[*state] = new_block;
+            }
+            // whether or not (block,letter) is already a splitter,
+            // adding (new_block,letter) is enough to keep the worklist correct
+            for other_letter in 0..num_letters {
+                if in_worklist.insert((new_block,other_letter)) {
+                    worklist.push((new_block,other_letter));
+                }
+            }
+        }
+    }
+    // ***
+    block_of
+}
@@ -0,0 +1,45 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+
+/// Moore's iterative refinement on a complete transition table
+/// where "delta[state][letter_index]" is the target of the transition.
+/// Two states stay in the same block as long as they have the same block and their targets are in the same blocks.
+/// Returns, for each state, the identifier of its block in the coarsest stable partition.
+pub fn moore_partition(delta : &[Vec<usize>],
+                       is_final : &[bool]) -> Vec<usize> {
+    let mut block_of : Vec<usize> = is_final.iter().map(|f| if *f {0} else {1}).collect();
+    let mut num_blocks = 0;
+    loop {
+        let mut signatures : HashMap<Vec<usize>,usize> = HashMap::new();
+        let mut new_block_of = vec![];
+        for (state,targets) in delta.iter().enumerate() {
+            let mut signature = vec![block_of[state]];
+            signature.extend(targets.iter().map(|targ| block_of[*targ]));
+            let next_id = signatures.len();
+            new_block_of.push(*signatures.entry(signature).or_insert(next_id));
+        }
+        block_of = new_block_of;
+        // the partition can only be refined so it is stable as soon as the number of blocks stays the same
+        if signatures.len() == num_blocks {
+            return block_of;
+        }
+        num_blocks = signatures.len();
+    }
+}
@@ -0,0 +1,188 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use num::{BigUint, One, Zero};
+use rand::Rng;
+
+use crate::dfa::dfa::AutDFA;
+use crate::traits::letter::AutLetter;
+
+
+/// Numbers of paths of each length from each state of a DFA to its final states.
+/// Because the DFA is deterministic, these are the numbers of accepted words
+/// and accepted words of a given length can be put in bijection with integers following the lexicographic order.
+pub struct DfaPathsCounter<'a, Letter : AutLetter> {
+    dfa : &'a AutDFA<Letter>,
+    letters : Vec<Letter>,
+    // "counts[k][state]" is the number of words of length k leading from state to a final state
+    counts : Vec<Vec<BigUint>>
+}
+
+impl<'a, Letter : AutLetter> DfaPathsCounter<'a, Letter> {
+
+    pub fn new(dfa : &'a AutDFA<Letter>, length : usize) -> Self {
+        let mut letters : Vec<Letter> = dfa.alphabet.iter().cloned().collect();
+        letters.sort();
+        let mut counts : Vec<Vec<BigUint>> = vec![(0..dfa.transitions.len())
+            .map(|state| if dfa.finals.contains(&state) {BigUint::one()} else {BigUint::zero()})
+            .collect()];
+        for k in 1..=length {
+            let next = dfa.transitions.iter()
+                .map(|transitions| transitions.values().map(|targ| &counts[k-1][*targ]).sum())
+                .collect();
+            counts.push(next);
+        }
+        DfaPathsCounter{dfa,letters,counts}
+    }
+
+    /// Returns the number of accepted words of the given length, which must not exceed the one given at construction
+    pub fn count(&self, length : usize) -> &BigUint {
+        &self.counts[length][self.dfa.initial]
+    }
+
+    /// Returns the position of *word* in the lexicographic order of the accepted words of the same length
+    /// or None if it is not accepted
+    pub fn rank(&self, word : &[Letter]) -> Option<BigUint> {
+        let mut rank = BigUint::zero();
+        let mut state = self.dfa.initial;
+        for (position,letter) in word.iter().enumerate() {
+            let remaining = word.len() - position - 1;
+            // all the words which begin with a smaller letter come before
+            for smaller in self.letters.iter().take_while(|x| *x < letter) {
+                if let Some(targ) = self.dfa.transitions[state].get(smaller) {
+                    rank += &self.counts[remaining][*targ];
+                }
+            }
+            state = *self.dfa.transitions[state].get(letter)?;
+        }
+        if self.dfa.finals.contains(&state) {
+            Some(rank)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the accepted word of length *length* at position *index* in the lexicographic order
+    /// or None if there are not that many accepted words
+    pub fn unrank(&self, index : &BigUint, length : usize) -> Option<Vec<Letter>> {
+        if index >= self.count(length) {
+            return None;
+        }
+        let mut index = index.clone();
+        let mut word = vec![];
+        let mut state = self.dfa.initial;
+        for remaining in (0..length).rev() {
+            for letter in &self.letters {
+                if let Some(targ) = self.dfa.transitions[state].get(letter) {
+                    let count = &self.counts[remaining][*targ];
+                    if &index < count {
+                        word.push(*letter);
+                        state = *targ;
+                        break;
+                    }
+                    index -= count;
+                }
+            }
+        }
+        Some(word)
+    }
+
+    /// Returns an accepted word of length *length* drawn uniformly at random
+    /// or None if there are no accepted words of that length
+    pub fn sample_uniform<R : Rng + ?Sized>(&self, length : usize, rng : &mut R) -> Option<Vec<Letter>> {
+        let total = self.count(length);
+        if total.is_zero() {
+            return None;
+        }
+        let index = uniform_below(total,rng);
+        self.unrank(&index,length)
+    }
+}
+
+
+/// Draws an integer uniformly in [0,bound) by rejection sampling on the bits of *bound*
+fn uniform_below<R : Rng + ?Sized>(bound : &BigUint, rng : &mut R) -> BigUint {
+    let bits = bound.bits();
+    let num_bytes = bits.div_ceil(8) as usize;
+    let excess_bits = (num_bytes as u64) * 8 - bits;
+    loop {
+        let mut bytes = vec![0_u8;num_bytes];
+        rng.fill(&mut bytes[..]);
+        // the most significant byte is masked so that the candidate has at most as many bits as the bound
+        if let Some(most_significant) = bytes.last_mut() {
+            *most_significant &= 0xff_u8 >> excess_bits;
+        }
+        let candidate = BigUint::from_bytes_le(&bytes);
+        if &candidate < bound {
+            return candidate;
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use num::BigUint;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::dfa::algos::sampling::DfaPathsCounter;
+    use crate::traits::enumerate::AutEnumerable;
+    use crate::tests_utils::get_even_a;
+
+    #[test]
+    fn rank_unrank_test() {
+        let dfa = get_even_a(hashset!{'a','b','c'});
+        let counter = DfaPathsCounter::new(&dfa,4);
+        let words : Vec<Vec<char>> = dfa.accepted_words().with_max_length(4)
+            .filter(|w| w.len() == 4)
+            .collect();
+        assert_eq!(BigUint::from(words.len()), *counter.count(4));
+        for (index,word) in words.iter().enumerate() {
+            assert_eq!(counter.rank(word), Some(BigUint::from(index)));
+            assert_eq!(counter.unrank(&BigUint::from(index),4).as_ref(), Some(word));
+        }
+        assert_eq!(counter.rank(&['a','b','b','b']), None);
+        assert_eq!(counter.unrank(&BigUint::from(words.len()),4), None);
+    }
+
+    #[test]
+    fn seeded_sampling_test() {
+        let dfa = get_even_a(hashset!{'a','b','c'});
+        let counter = DfaPathsCounter::new(&dfa,6);
+        let draw = |seed : u64| -> Vec<Vec<char>> {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20).map(|_| counter.sample_uniform(6,&mut rng).unwrap()).collect()
+        };
+        let samples = draw(42);
+        assert_eq!(samples, draw(42));
+        for word in &samples {
+            assert_eq!(word.len(), 6);
+            assert_eq!(word.iter().filter(|l| **l == 'a').count() % 2, 0);
+        }
+        // each of the 365 words is drawn with the same probability
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut hits = vec![0;365];
+        for _ in 0..36500 {
+            let word = counter.sample_uniform(6,&mut rng).unwrap();
+            let rank : usize = counter.rank(&word).unwrap().try_into().unwrap();
+            hits[rank] += 1;
+        }
+        assert!(hits.iter().all(|h| *h > 40 && *h < 180));
+    }
+}
@@ -15,11 +15,22 @@ limitations under the License.
 */
 
 
+use crate::dfa::algos::equivalence::hopcroft_karp_distinguishing_word;
 use crate::dfa::dfa::AutDFA;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::letter::AutLetter;
+use crate::traits::transform::AutTransformable;
 use crate::traits::translate::AutTranslatable;
 
+impl<Letter : AutLetter> AutDFA<Letter> {
+
+    /// Returns None if *self* and *other* define the same language and otherwise a word accepted by exactly one of them (see "hopcroft_karp_distinguishing_word")
+    pub fn get_distinguishing_word(&self, other : &Self) -> Option<Vec<Letter>> {
+        hopcroft_karp_distinguishing_word(self,other)
+    }
+
+}
+
 impl<Letter : AutLetter> AutCharacterizable<Letter> for AutDFA<Letter> {
 
     fn is_complete(&self) -> bool {
@@ -46,8 +57,56 @@ impl<Letter : AutLetter> AutCharacterizable<Letter> for AutDFA<Letter> {
                 other: &Self) -> bool {
         self.to_nfa().contains(&other.to_nfa())
     }
-}
 
+    // Hopcroft-Karp
+    fn equals(&self,
+              other: &Self) -> bool {
+        hopcroft_karp_distinguishing_word(self,other).is_none()
+    }
+
+    fn find_accepted_word(&self) -> Option<Vec<Letter>> {
+        self.to_nfa().find_accepted_word()
+    }
+
+    // a shortest word leading to a non-final state of the completed DFA
+    fn find_rejected_word(&self) -> Option<Vec<Letter>> {
+        self.clone().negate().find_accepted_word()
+    }
 
+    fn find_inclusion_counterexample(&self,
+                                     other: &Self) -> Option<Vec<Letter>> {
+        self.to_nfa().find_inclusion_counterexample(&other.to_nfa())
+    }
+}
 
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::dfa::dfa::AutDFA;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::{check_witness_words, WITNESS_TEST_LANGUAGES};
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
 
+    #[test]
+    fn dfa_witness_words_test() {
+        let alphabet = hashset!{'a','b'};
+        let languages : Vec<AutDFA<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap().to_dfa())
+            .collect();
+        for aut1 in &languages {
+            for aut2 in &languages {
+                check_witness_words(aut1,aut2,&|aut,word| aut.runs_trace(word).unwrap(),4);
+            }
+        }
+        // no witness for the empty language, the universal language and included languages
+        assert_eq!(languages[0].find_accepted_word(), None);
+        assert_eq!(languages[1].find_rejected_word(), None);
+        assert_eq!(languages[4].find_inclusion_counterexample(&languages[3]), None);
+        assert_eq!(languages[1].find_inclusion_counterexample(&languages[6]), None);
+        assert_eq!(languages[3].find_inclusion_counterexample(&languages[4]).map(|word| word.len()), Some(3));
+        assert_eq!(languages[6].find_rejected_word(), Some(vec!['b','a']));
+    }
+
+}
@@ -0,0 +1,67 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use num::BigUint;
+use rand::Rng;
+
+use crate::dfa::algos::counting::{count_accepted_words_of_length, count_accepted_words_up_to_length, get_generating_function, get_language_growth, LanguageGrowth, RationalGeneratingFunction};
+use crate::dfa::algos::sampling::DfaPathsCounter;
+use crate::dfa::dfa::AutDFA;
+use crate::traits::letter::AutLetter;
+
+
+impl<Letter : AutLetter> AutDFA<Letter> {
+
+    /// Returns the number of accepted words of length *length*
+    pub fn count_accepted_words_of_length(&self, length : usize) -> BigUint {
+        count_accepted_words_of_length(self,length)
+    }
+
+    /// Returns the number of accepted words of length at most *length*
+    pub fn count_accepted_words_up_to_length(&self, length : usize) -> BigUint {
+        count_accepted_words_up_to_length(self,length)
+    }
+
+    /// Returns the generating function of the numbers of accepted words of each length (see "get_generating_function")
+    pub fn get_generating_function(&self) -> RationalGeneratingFunction {
+        get_generating_function(self)
+    }
+
+    /// Returns how the number of accepted words of length n grows with n (see "get_language_growth")
+    pub fn get_language_growth(&self) -> LanguageGrowth {
+        get_language_growth(self)
+    }
+
+    /// Returns an accepted word of length *length* drawn uniformly at random using *rng*
+    /// or None if there are no accepted words of that length.
+    /// Using a seeded generator (e.g. "StdRng::seed_from_u64") makes the draws reproducible.
+    pub fn sample_uniform<R : Rng + ?Sized>(&self, length : usize, rng : &mut R) -> Option<Vec<Letter>> {
+        DfaPathsCounter::new(self,length).sample_uniform(length,rng)
+    }
+
+    /// Returns the position of *word* in the lexicographic order of the accepted words of the same length
+    /// or None if it is not accepted
+    pub fn rank(&self, word : &[Letter]) -> Option<BigUint> {
+        DfaPathsCounter::new(self,word.len()).rank(word)
+    }
+
+    /// Returns the accepted word of length *length* at position *index* in the lexicographic order of the accepted words of that length
+    /// or None if there are not that many accepted words
+    pub fn unrank(&self, index : &BigUint, length : usize) -> Option<Vec<Letter>> {
+        DfaPathsCounter::new(self,length).unrank(index,length)
+    }
+
+}
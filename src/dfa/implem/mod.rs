@@ -21,4 +21,8 @@ mod transform;
 mod to_dot;
 mod run;
 mod translate;
-mod letter;
\ No newline at end of file
+mod letter;
+mod enumerate;
+mod count;
+#[cfg(feature = "serde")]
+mod serialize;
@@ -0,0 +1,107 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::dfa::dfa::AutDFA;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "initial":state, "finals":[state,..], "transitions":[[[letter,target],..],..]}
+/// where "transitions" lists, for each state, its outgoing transitions sorted by letter
+#[derive(Serialize, Deserialize)]
+struct DFALayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    initial : usize,
+    finals : BTreeSet<usize>,
+    transitions : Vec<Vec<(Letter,usize)>>
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for AutDFA<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DFALayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            initial : self.initial,
+            finals : self.finals.iter().cloned().collect(),
+            transitions : self.transitions.iter()
+                .map(|map| map.iter().map(|(letter,targ)| (*letter,*targ)).sorted().collect())
+                .collect()
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for AutDFA<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = DFALayout::<Letter>::deserialize(deserializer)?;
+        let mut transitions = vec![];
+        for (orig,outgoing) in layout.transitions.into_iter().enumerate() {
+            let map_len = outgoing.len();
+            let map : HashMap<Letter,usize> = outgoing.into_iter().collect();
+            if map.len() != map_len {
+                return Err(D::Error::custom(AutError::<Letter>::Other(format!("several transitions with the same letter from state {:}", orig))));
+            }
+            transitions.push(map);
+        }
+        AutDFA::from_raw(layout.alphabet.into_iter().collect(),
+                         layout.initial,
+                         layout.finals.into_iter().collect(),
+                         transitions).map_err(D::Error::custom)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::dfa::dfa::AutDFA;
+
+    /// accepts the words over {a,b} which end with 'a'
+    fn get_example() -> AutDFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, usize>> = vec![
+            hashmap!{'b' => 0, 'a' => 1},
+            hashmap!{'b' => 0, 'a' => 1}
+        ];
+        AutDFA::<char>::from_raw(alphabet, 0, hashset!{1}, transitions).unwrap()
+    }
+
+    #[test]
+    fn dfa_serialization_test() {
+        let dfa = get_example();
+        let json = serde_json::to_string(&dfa).unwrap();
+        assert_eq!(json, r#"{"alphabet":["a","b"],"initial":0,"finals":[1],"transitions":[[["a",1],["b",0]],[["a",1],["b",0]]]}"#);
+        let got : AutDFA<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.initial, dfa.initial);
+        assert_eq!(got.finals, dfa.finals);
+        assert_eq!(got.transitions, dfa.transitions);
+        // the target of a transition is not a state
+        let invalid = r#"{"alphabet":["a"],"initial":0,"finals":[],"transitions":[[["a",1]]]}"#;
+        assert!(serde_json::from_str::<AutDFA<char>>(invalid).is_err());
+        // a letter which is not in the alphabet
+        let invalid = r#"{"alphabet":["a"],"initial":0,"finals":[],"transitions":[[["b",0]]]}"#;
+        assert!(serde_json::from_str::<AutDFA<char>>(invalid).is_err());
+        // non-deterministic transitions
+        let invalid = r#"{"alphabet":["a"],"initial":0,"finals":[],"transitions":[[["a",0],["a",1]],[]]}"#;
+        assert!(serde_json::from_str::<AutDFA<char>>(invalid).is_err());
+    }
+}
@@ -16,8 +16,9 @@ limitations under the License.
 
 use std::collections::HashMap;
 
+use crate::dfa::algos::minimization::algo::{minimize_dfa, MinimizationAlgorithm};
 use crate::dfa::dfa::AutDFA;
-use crate::traits::build::AutBuildable;
+use crate::nfait::algos::product::{shuffle_product, synchronous_product};
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::error::AutError;
 use crate::traits::letter::AutLetter;
@@ -25,6 +26,15 @@ use crate::traits::transform::AutTransformable;
 use crate::traits::translate::AutTranslatable;
 
 
+impl<Letter : AutLetter> AutDFA<Letter> {
+
+    /// Returns a minimal DFA obtained with the given algorithm along with a map associating each state of *self* to its equivalence class (see "minimize_dfa")
+    pub fn minimize_with(&self, algorithm : MinimizationAlgorithm) -> (Self,Vec<Option<usize>>) {
+        minimize_dfa(self,algorithm)
+    }
+
+}
+
 impl<Letter : AutLetter> AutTransformable<Letter> for AutDFA<Letter> {
 
     fn complete(mut self) -> Self {
@@ -61,24 +71,31 @@ impl<Letter : AutLetter> AutTransformable<Letter> for AutDFA<Letter> {
         self.to_nfa().reverse().to_dfa()
     }
 
-    // Brzozowski
+    // Hopcroft
     fn minimize(self) -> Self {
-        self.reverse().reverse()
+        minimize_dfa(&self,MinimizationAlgorithm::Hopcroft).0
     }
 
-    // De Morgan
+    // product of the accessible pairs of states
     fn intersect(self,
                  other: Self) -> Result<Self,AutError<Letter>> {
-        match self.negate().unite(other.negate()) {
-            Err(e) => {Err(e)},
-            Ok(got) => {Ok(got.negate())}
-        }
+        let product = synchronous_product(&self.to_nfait(),&other.to_nfait(),&|l,r| l && r)?;
+        Ok(product.to_dfa())
+    }
+
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        // other is completed so that a word rejected because other is blocked still reaches a pair of states
+        let product = synchronous_product(&self.to_nfait(),&other.complete().to_nfait(),&|l,r| l && !r)?;
+        Ok(product.to_dfa())
+    }
+
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        let product = synchronous_product(&self.complete().to_nfait(),&other.complete().to_nfait(),&|l,r| l != r)?;
+        Ok(product.to_dfa())
     }
 
     fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
-        match self.to_nfa().interleave(other.to_nfa()) {
-            Err(e) => {Err(e)},
-            Ok(got) => {Ok(got.to_dfa())}
-        }
+        let product = shuffle_product(&self.to_nfait(),&other.to_nfait())?;
+        Ok(product.to_dfa())
     }
 }
@@ -16,4 +16,5 @@ limitations under the License.
 
 
 pub mod dfa;
-mod implem;
\ No newline at end of file
+mod implem;
+pub mod algos;
\ No newline at end of file
@@ -0,0 +1,139 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::dfa::dfa::AutDFA;
+use crate::ere::term::TermERE;
+use crate::nfa::nfa::AutNFA;
+use crate::traits::build::AutBuildable;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::transform::AutTransformable;
+use crate::traits::translate::AutTranslatable;
+
+
+/// Compiles *term* into a minimal DFA over *alphabet* by compiling its sub-terms bottom-up
+/// and combining them with the operations of the DFA layer.
+/// The wildcard matches any letter of *alphabet* and negations are complements w.r.t. words over *alphabet*.
+/// Intermediate DFAs are minimized so that nested negations and intersections stay tractable.
+/// Returns an error if *term* contains a repetition whose minimum exceeds its maximum.
+pub fn term_ere_to_dfa<Letter : AutLetter>(term : &TermERE<Letter>,
+                                           alphabet : &HashSet<Letter>) -> Result<AutDFA<Letter>,AutError<Letter>> {
+    term.check_repetitions()?;
+    Ok(compile_term(term,alphabet))
+}
+
+fn compile_term<Letter : AutLetter>(term : &TermERE<Letter>,
+                                    alphabet : &HashSet<Letter>) -> AutDFA<Letter> {
+    let dfa = match term {
+        TermERE::Empty => {
+            AutNFA::new_accepts_nothing(alphabet.clone()).to_dfa()
+        },
+        TermERE::Epsilon => {
+            AutNFA::new_empty_word(alphabet.clone()).to_dfa()
+        },
+        TermERE::Literal(letter) => {
+            AutNFA::new_matching(alphabet.clone(),&[*letter]).to_dfa()
+        },
+        TermERE::Wildcard => {
+            AutNFA::new_length(alphabet.clone(),1).to_dfa()
+        },
+        TermERE::Union(sub_terms) => {
+            sub_terms.iter()
+                .fold(AutNFA::new_accepts_nothing(alphabet.clone()).to_dfa(),
+                      |acc,t| acc.unite(compile_term(t,alphabet)).unwrap())
+        },
+        TermERE::Concat(sub_terms) => {
+            sub_terms.iter()
+                .fold(AutNFA::new_empty_word(alphabet.clone()).to_dfa(),
+                      |acc,t| acc.concatenate(compile_term(t,alphabet)).unwrap())
+        },
+        TermERE::Intersection(sub_terms) => {
+            sub_terms.iter()
+                .fold(AutNFA::new_universal(alphabet.clone()).to_dfa(),
+                      |acc,t| acc.intersect(compile_term(t,alphabet)).unwrap())
+        },
+        TermERE::Negation(sub_term) => {
+            compile_term(sub_term,alphabet).negate()
+        },
+        TermERE::Repeat(sub_term,min,max) => {
+            let sub_dfa = compile_term(sub_term,alphabet);
+            match max {
+                None => sub_dfa.repeat_range(*min..),
+                Some(max) => sub_dfa.repeat_range(*min..=*max)
+            }
+        }
+    };
+    dfa.minimize()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::ere::algos::compilation::term_ere_to_dfa;
+    use crate::ere::ere::ExpERE;
+    use crate::ere::term::TermERE;
+    use crate::parsers::ere_parser::parse_ere;
+    use crate::traits::build::AutBuildable;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::letter::AutAlphabetSubstitutable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    #[test]
+    fn compilation_test() {
+        let alphabet = hashset!{'a','b'};
+        // words of length 2 to 4 which do not contain "aa" and which do not end with 'b'
+        let term = parse_ere(".{2,4} ∩ ¬(.*aa.*) ∩ ¬(.*b)",&alphabet).unwrap();
+        let dfa = term_ere_to_dfa(&term,&alphabet).unwrap();
+        for word in get_all_words(6) {
+            let contains_aa = word.windows(2).any(|w| w == ['a','a']);
+            let expected = (2..=4).contains(&word.len()) && !contains_aa && word.last() != Some(&'b');
+            assert_eq!(dfa.runs_trace(&word).unwrap(), expected, "{:?}", word);
+        }
+        // a repetition whose minimum exceeds its maximum is rejected instead of panicking
+        let invalid = TermERE::Repeat(Box::new(TermERE::Literal('a')),3,Some(1));
+        assert!(term_ere_to_dfa(&invalid,&alphabet).is_err());
+    }
+
+    #[test]
+    fn ere_formalism_test() {
+        let alphabet = hashset!{'a','b'};
+        // words in which each 'a' is followed by a 'b'
+        let ere = ExpERE::from_raw(alphabet.clone(),parse_ere("¬(.*a)∩¬(.*aa.*)",&alphabet).unwrap()).unwrap();
+        let bre = ere.to_bre();
+        for word in get_all_words(6) {
+            assert_eq!(ere.runs_trace(&word).unwrap(), bre.matches(&word));
+        }
+        let expected = ExpERE::from_raw(alphabet.clone(),parse_ere("(b|ab)*",&alphabet).unwrap()).unwrap();
+        assert!(ere.equals(&expected));
+        assert!(ere.clone().repeat_range(2..=3).equals(&expected));
+        // hiding 'a' goes through the DFA layer because of the negations
+        let hidden = ere.hide_letters(false,&|letter| *letter == 'a');
+        let expected = ExpERE::from_raw(alphabet.clone(),parse_ere("b*",&alphabet).unwrap()).unwrap();
+        assert!(hidden.equals(&expected));
+        assert!(hidden.runs_trace(&['c']).is_err());
+        // the minimum of a repetition must not exceed its maximum, even when nested
+        let invalid = TermERE::Negation(Box::new(TermERE::Repeat(Box::new(TermERE::Literal('a')),3,Some(1))));
+        assert!(ExpERE::from_raw(alphabet.clone(),invalid).is_err());
+        let valid = TermERE::Repeat(Box::new(TermERE::Literal('a')),1,Some(1));
+        assert!(ExpERE::from_raw(alphabet,valid).is_ok());
+    }
+}
@@ -0,0 +1,239 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::bre::term::TermBRE;
+use crate::ere::algos::compilation::term_ere_to_dfa;
+use crate::ere::term::TermERE;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::translate::AutTranslatable;
+
+
+/// Returns a BRE term expressing the same language as *term* over *alphabet*.
+///
+/// Wildcards are expanded into unions of the letters of *alphabet* and
+/// bounded repetitions are expanded syntactically e.g. x{2,4} becomes x.x.(x.(x|𝜀)|𝜀),
+/// so that the size of the result is linear in the bounds.
+/// Only the sub-terms rooted in an intersection or a negation are compiled into automata
+/// and translated back via state elimination.
+/// Returns an error if *term* contains a repetition whose minimum exceeds its maximum.
+pub fn term_ere_to_term_bre<Letter : AutLetter>(term : &TermERE<Letter>,
+                                                alphabet : &HashSet<Letter>) -> Result<TermBRE<Letter>,AutError<Letter>> {
+    term.check_repetitions()?;
+    Ok(expand_term(term,alphabet))
+}
+
+fn expand_term<Letter : AutLetter>(term : &TermERE<Letter>,
+                                   alphabet : &HashSet<Letter>) -> TermBRE<Letter> {
+    match term {
+        TermERE::Empty => TermBRE::Empty,
+        TermERE::Epsilon => TermBRE::Epsilon,
+        TermERE::Literal(letter) => TermBRE::Literal(*letter),
+        TermERE::Wildcard => {
+            alphabet.iter().fold(TermBRE::Empty,|acc,letter| acc.unite(TermBRE::Literal(*letter)))
+        },
+        TermERE::Union(sub_terms) => {
+            sub_terms.iter()
+                .fold(TermBRE::Empty,|acc,t| acc.unite(expand_term(t,alphabet)))
+        },
+        TermERE::Concat(sub_terms) => {
+            sub_terms.iter()
+                .fold(TermBRE::Epsilon,|acc,t| acc.concatenate(expand_term(t,alphabet)))
+        },
+        TermERE::Repeat(sub_term,min,max) => {
+            let sub_bre = expand_term(sub_term,alphabet);
+            let optional_part = match max {
+                None => {
+                    match sub_bre {
+                        TermBRE::Empty | TermBRE::Epsilon => TermBRE::Epsilon,
+                        _ => TermBRE::Kleene(Box::new(sub_bre.clone()))
+                    }
+                },
+                Some(max) => {
+                    // nested options (x.(x.(...|𝜀)|𝜀)|𝜀)
+                    (*min..*max).fold(TermBRE::Epsilon,
+                                      |acc,_| sub_bre.clone().concatenate(acc).unite(TermBRE::Epsilon))
+                }
+            };
+            (0..*min).fold(TermBRE::Epsilon,|acc,_| acc.concatenate(sub_bre.clone()))
+                .concatenate(optional_part)
+        },
+        TermERE::Intersection(_) | TermERE::Negation(_) => {
+            term_ere_to_dfa(term,alphabet).unwrap().to_bre().term
+        }
+    }
+}
+
+
+/// Returns an ERE term expressing the same language as *term*, using the operators of ERE to make it compact:
+/// - (x|𝜀) becomes x? (and 𝜀 is simply removed from unions which already express it)
+/// - x* becomes x{0,} which is printed as x*
+/// - consecutive repetitions of the same term in a concatenation are merged into a single bounded repetition
+///   e.g. x.x.x becomes x{3}, x.x* and x*.x become x+ and a.b.(a.b)* becomes (a.b)+
+pub fn term_bre_to_term_ere<Letter : AutLetter>(term : &TermBRE<Letter>) -> TermERE<Letter> {
+    match term {
+        TermBRE::Empty => TermERE::Empty,
+        TermBRE::Epsilon => TermERE::Epsilon,
+        TermBRE::Literal(letter) => TermERE::Literal(*letter),
+        TermBRE::Kleene(sub_term) => {
+            TermERE::Repeat(Box::new(term_bre_to_term_ere(sub_term)),0,None)
+        },
+        TermBRE::Union(sub_terms) => {
+            let has_epsilon = sub_terms.contains(&TermBRE::Epsilon);
+            let others : Vec<&TermBRE<Letter>> = sub_terms.iter().filter(|t| **t != TermBRE::Epsilon).collect();
+            let others_express_epsilon = others.iter().any(|t| t.expresses_epsilon());
+            let union = others.into_iter()
+                .fold(TermERE::Empty,|acc,t| acc.unite(term_bre_to_term_ere(t)));
+            match union {
+                TermERE::Empty => {
+                    if has_epsilon {TermERE::Epsilon} else {TermERE::Empty}
+                },
+                _ => {
+                    if has_epsilon && !others_express_epsilon {
+                        TermERE::Repeat(Box::new(union),0,Some(1))
+                    } else {
+                        union
+                    }
+                }
+            }
+        },
+        TermBRE::Concat(sub_terms) => {
+            fold_repetitions(sub_terms.iter().map(term_bre_to_term_ere).collect())
+        }
+    }
+}
+
+/// A repetition of *base* between *min* and *max* times
+struct FoldedFactor<Letter : AutLetter> {
+    base : TermERE<Letter>,
+    min : usize,
+    max : Option<usize>
+}
+
+impl<Letter : AutLetter> FoldedFactor<Letter> {
+
+    fn from_term(term : TermERE<Letter>) -> Self {
+        match term {
+            TermERE::Repeat(base,min,max) => FoldedFactor{base:*base,min,max},
+            _ => FoldedFactor{base:term,min:1,max:Some(1)}
+        }
+    }
+
+    fn is_single(&self, term : &TermERE<Letter>) -> bool {
+        self.min == 1 && self.max == Some(1) && self.base == *term
+    }
+
+    /// x{a,b}.x{c,d} = x{a+c,b+d}
+    fn absorb(&mut self, min : usize, max : Option<usize>) {
+        self.min += min;
+        self.max = match (self.max,max) {
+            (Some(m1),Some(m2)) => Some(m1 + m2),
+            _ => None
+        };
+    }
+
+    fn into_term(self) -> TermERE<Letter> {
+        match (self.min,self.max) {
+            (1,Some(1)) => self.base,
+            _ => TermERE::Repeat(Box::new(self.base),self.min,self.max)
+        }
+    }
+
+}
+
+fn fold_repetitions<Letter : AutLetter>(factors : Vec<TermERE<Letter>>) -> TermERE<Letter> {
+    let mut folded : Vec<FoldedFactor<Letter>> = vec![];
+    for factor in factors {
+        let factor = FoldedFactor::from_term(factor);
+        if let Some(last) = folded.last_mut() {
+            if last.base == factor.base {
+                last.absorb(factor.min,factor.max);
+                continue;
+            }
+        }
+        // a repeated concatenation may absorb the single occurrences of its factors which precede it
+        if let TermERE::Concat(block) = &factor.base {
+            let num = block.len();
+            if folded.len() >= num && folded[folded.len() - num..].iter().zip(block.iter()).all(|(f,t)| f.is_single(t)) {
+                folded.truncate(folded.len() - num);
+                let mut factor = factor;
+                factor.absorb(1,Some(1));
+                folded.push(factor);
+                continue;
+            }
+        }
+        folded.push(factor);
+    }
+    match folded.len() {
+        0 => TermERE::Epsilon,
+        1 => folded.pop().unwrap().into_term(),
+        _ => TermERE::Concat(folded.into_iter().map(|f| f.into_term()).collect())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::ere::algos::conversion::{term_bre_to_term_ere, term_ere_to_term_bre};
+    use crate::ere::ere::ExpERE;
+    use crate::ere::term::TermERE;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::parsers::ere_parser::parse_ere;
+    use crate::printers::p_chars::CharAsLetterPrinter;
+    use crate::random::params::RandomTermParameters;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::repr::ExpBREPrintable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::for_each_random_term;
+
+    #[test]
+    fn ere_to_bre_test() {
+        let alphabet = hashset!{'a','b','c'};
+        for text in ["a{2,4}", "(a|.)+b?", "c{3}(¬(a*)∩.{2})", "(ab){,2}|∅*"] {
+            let term = parse_ere(text,&alphabet).unwrap();
+            let bre = ExpBRE::from_raw(alphabet.clone(),term_ere_to_term_bre(&term,&alphabet).unwrap()).unwrap();
+            let ere = ExpERE::from_raw(alphabet.clone(),term).unwrap();
+            assert!(ere.to_dfa().equals(&bre.to_dfa()), "{}", text);
+        }
+        // a repetition whose minimum exceeds its maximum is rejected instead of being translated into a{3}
+        let invalid = TermERE::Repeat(Box::new(TermERE::Literal('a')),3,Some(1));
+        assert!(term_ere_to_term_bre(&invalid,&alphabet).is_err());
+        assert!(invalid.to_bre(&alphabet).is_err());
+    }
+
+    #[test]
+    fn bre_to_ere_test() {
+        let alphabet = hashset!{'a','b','c'};
+        let printer = CharAsLetterPrinter{};
+        for (text,expected) in [("aaa","a{3}"), ("aa*","a+"), ("b*bb","b{2,}"), ("ab(ab)*c","(ab)+c"), ("(a|b|𝜀)c","(a|b)?c"), ("a(a|𝜀)","a{1,2}")] {
+            let bre = parse_bre(text,&alphabet).unwrap();
+            let ere = term_bre_to_term_ere(&bre.term);
+            assert_eq!(ere.regexp_to_string(false,&printer), expected);
+        }
+        // the translation preserves the language
+        let params = RandomTermParameters::new(alphabet.clone(),5,15);
+        for_each_random_term(19,50,&params,|term| {
+            let bre = ExpBRE::from_raw(alphabet.clone(),term).unwrap();
+            let ere = ExpERE::from_raw(alphabet.clone(),term_bre_to_term_ere(&bre.term)).unwrap();
+            assert!(ere.to_dfa().equals(&bre.to_dfa()));
+        });
+    }
+}
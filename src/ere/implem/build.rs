@@ -0,0 +1,89 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::ops::RangeBounds;
+use std::ops::Bound::{Included,Excluded,Unbounded};
+
+use crate::ere::ere::ExpERE;
+use crate::ere::term::TermERE;
+
+use crate::traits::letter::AutLetter;
+use crate::traits::build::AutBuildable;
+use crate::traits::error::AutError;
+
+impl<Letter: AutLetter> AutBuildable<Letter> for ExpERE<Letter> {
+
+    fn unite(mut self, other : Self) -> Result<Self,AutError<Letter>> {
+        if self.alphabet != other.alphabet {
+            return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(self.alphabet,
+                                                                            other.alphabet));
+        }
+        self.term = self.term.unite(other.term);
+        Ok(self)
+    }
+
+    fn concatenate(mut self, other: Self) -> Result<Self,AutError<Letter>> {
+        if self.alphabet != other.alphabet {
+            return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(self.alphabet,
+                                                                            other.alphabet));
+        }
+        self.term = self.term.concatenate(other.term);
+        Ok(self)
+    }
+
+    fn repeat(mut self, num : usize) -> Self {
+        self.term = TermERE::Repeat(Box::new(self.term),num,Some(num));
+        self
+    }
+
+    fn kleene(mut self) -> Self {
+        self.term = TermERE::Repeat(Box::new(self.term),0,None);
+        self
+    }
+
+    fn at_most(mut self, num: usize) -> Self {
+        self.term = TermERE::Repeat(Box::new(self.term),0,Some(num));
+        self
+    }
+
+    fn at_least(mut self, num: usize) -> Self {
+        self.term = TermERE::Repeat(Box::new(self.term),num,None);
+        self
+    }
+
+    fn repeat_range<R: RangeBounds<usize>>(mut self, r: R) -> Self {
+        let start = match r.start_bound() {
+            Included(&a) => a,
+            Excluded(&a) => a + 1,
+            Unbounded => 0,
+        };
+        // ***
+        let end_opt = match r.end_bound() {
+            Included(&a) => Some(a),
+            Excluded(&a) => Some(a - 1),
+            Unbounded => None,
+        };
+        // ***
+        if let Some(end) = end_opt {
+            if end < start {
+                panic!()
+            }
+        }
+        self.term = TermERE::Repeat(Box::new(self.term),start,end_opt);
+        self
+    }
+
+}
@@ -18,15 +18,30 @@ limitations under the License.
 use maplit::btreeset;
 
 
-use crate::{ere::term::TermERE, traits::letter::{get_new_alphabet_from_hiding, get_new_alphabet_from_substitution, AutAlphabetSubstitutable, AutLetter}};
+use crate::ere::ere::ExpERE;
+use crate::ere::term::TermERE;
+use crate::traits::letter::{AutAlphabetSubstitutable, AutLetter, get_new_alphabet_from_hiding, get_new_alphabet_from_substitution};
+use crate::traits::translate::AutTranslatable;
 
 
-impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for TermERE<Letter> {
+/// Letter morphisms are not exposed on bare terms because the wildcard, intersection and negation
+/// depend on the alphabet : they are only applied to terms which commute with them (see "commutes_with_letter_morphisms")
+impl<Letter: AutLetter> TermERE<Letter> {
 
+    /// Substitutions and hidings can be applied directly to the term
+    /// only if it does not involve operators which depend on the alphabet or which do not commute with them
+    fn commutes_with_letter_morphisms(&self) -> bool {
+        match self {
+            TermERE::Wildcard | TermERE::Intersection(_) | TermERE::Negation(_) => false,
+            TermERE::Union(sub_terms) => sub_terms.iter().all(|t| t.commutes_with_letter_morphisms()),
+            TermERE::Concat(sub_terms) => sub_terms.iter().all(|t| t.commutes_with_letter_morphisms()),
+            TermERE::Repeat(sub_term,_,_) => sub_term.commutes_with_letter_morphisms(),
+            _ => true
+        }
+    }
 
-    fn substitute_letters(self,
-                          remove_from_alphabet : bool,
-                          substitution : &dyn Fn(&Letter) -> Letter) -> Self {
+    fn substitute_letters_in_term(self,
+                                  substitution : &dyn Fn(&Letter) -> Letter) -> Self {
         match self {
             TermERE::Empty => {TermERE::Empty},
             TermERE::Epsilon => {TermERE::Epsilon},
@@ -36,43 +51,28 @@ impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for TermERE<Letter> {
             TermERE::Union(sub_terms) => {
                 let mut subbed_terms = btreeset!{};
                 for sub_term in sub_terms {
-                    subbed_terms.insert(sub_term.substitute_letters(remove_from_alphabet,substitution));
+                    subbed_terms.insert(sub_term.substitute_letters_in_term(substitution));
                 }
                 TermERE::Union(subbed_terms)
             },
             TermERE::Concat(sub_terms) => {
                 let mut subbed_terms = vec!{};
                 for sub_term in sub_terms {
-                    subbed_terms.push(sub_term.substitute_letters(remove_from_alphabet,substitution));
+                    subbed_terms.push(sub_term.substitute_letters_in_term(substitution));
                 }
                 TermERE::Concat(subbed_terms)
             },
             TermERE::Repeat(sub_term,min,max) => {
-                TermERE::Repeat(Box::new(sub_term.substitute_letters(remove_from_alphabet,substitution)), min, max)
+                TermERE::Repeat(Box::new(sub_term.substitute_letters_in_term(substitution)), min, max)
             },
-            TermERE::Intersection(sub_terms) => {
-                let mut subbed_terms = btreeset!{};
-                for sub_term in sub_terms {
-                    subbed_terms.insert(sub_term.substitute_letters(remove_from_alphabet,substitution));
-                }
-                TermERE::Intersection(subbed_terms)
-            },
-            TermERE::Negation(sub_term) => {
-                TermERE::Negation(Box::new(sub_term.substitute_letters(remove_from_alphabet,substitution)))
-            },
-            TermERE::Wildcard => {
-                if remove_from_alphabet {
-                    TermERE::Wildcard
-                } else {
-                    unimplemented!()
-                }
+            TermERE::Wildcard | TermERE::Intersection(_) | TermERE::Negation(_) => {
+                unreachable!("letter morphisms are only applied to terms which commute with them")
             },
         }
     }
 
-    fn hide_letters(self,
-                    remove_from_alphabet : bool,
-                    should_hide : &dyn Fn(&Letter) -> bool) -> Self {
+    fn hide_letters_in_term(self,
+                            should_hide : &dyn Fn(&Letter) -> bool) -> Self {
         match self {
             TermERE::Epsilon => {TermERE::Epsilon},
             TermERE::Empty => {TermERE::Empty},
@@ -86,36 +86,56 @@ impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for TermERE<Letter> {
             TermERE::Union(sub_terms) => {
                 let mut new_term = TermERE::Empty;
                 for sub_term in sub_terms {
-                    new_term = new_term.unite(sub_term.hide_letters(remove_from_alphabet,should_hide));
+                    new_term = new_term.unite(sub_term.hide_letters_in_term(should_hide));
                 }
                 new_term
             },
             TermERE::Concat(sub_terms) => {
                 let mut new_term = TermERE::Epsilon;
                 for sub_term in sub_terms {
-                    new_term = new_term.concatenate(sub_term.hide_letters(remove_from_alphabet,should_hide));
+                    new_term = new_term.concatenate(sub_term.hide_letters_in_term(should_hide));
                 }
                 new_term
             },
             TermERE::Repeat(sub_term,min,max) => {
-                let hidden_sub_term = sub_term.hide_letters(remove_from_alphabet,should_hide);
-                unimplemented!()
-            },
-            TermERE::Intersection(sub_terms) => {
-                unimplemented!()
+                TermERE::Repeat(Box::new(sub_term.hide_letters_in_term(should_hide)), min, max)
             },
-            TermERE::Negation(sub_term) => {
-                unimplemented!()
-            },
-            TermERE::Wildcard => {
-                if remove_from_alphabet {
-                    TermERE::Wildcard
-                } else {
-                    unimplemented!()
-                }
+            TermERE::Wildcard | TermERE::Intersection(_) | TermERE::Negation(_) => {
+                unreachable!("letter morphisms are only applied to terms which commute with them")
             },
         }
     }
 }
 
 
+impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for ExpERE<Letter> {
+
+    fn substitute_letters(self,
+                          remove_from_alphabet : bool,
+                          substitution : &dyn Fn(&Letter) -> Letter) -> Self {
+        if self.term.commutes_with_letter_morphisms() {
+            ExpERE::from_raw(get_new_alphabet_from_substitution(&self.alphabet,remove_from_alphabet,substitution),
+                             self.term.substitute_letters_in_term(substitution)
+            ).unwrap()
+        } else {
+            // via the DFA layer
+            let bre = self.to_dfa().substitute_letters(remove_from_alphabet,substitution).to_bre();
+            ExpERE::from_raw(bre.alphabet,bre.term.to_ere()).unwrap()
+        }
+    }
+
+    fn hide_letters(self,
+                    remove_from_alphabet : bool,
+                    should_hide : &dyn Fn(&Letter) -> bool) -> Self {
+        if self.term.commutes_with_letter_morphisms() {
+            ExpERE::from_raw(get_new_alphabet_from_hiding(&self.alphabet,remove_from_alphabet,should_hide),
+                             self.term.hide_letters_in_term(should_hide)
+            ).unwrap()
+        } else {
+            // via the DFA layer
+            let bre = self.to_dfa().hide_letters(remove_from_alphabet,should_hide).to_bre();
+            ExpERE::from_raw(bre.alphabet,bre.term.to_ere()).unwrap()
+        }
+    }
+
+}
@@ -14,6 +14,12 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
+
 mod letter;
 mod tostring;
-
+mod translate;
+mod build;
+mod characterize;
+mod run;
+#[cfg(feature = "serde")]
+pub(crate) mod serialize;
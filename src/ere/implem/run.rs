@@ -0,0 +1,81 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::ere::ere::ExpERE;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::run::AutRunnable;
+use crate::traits::translate::AutTranslatable;
+
+
+/// The states which are run through are those of the minimal DFA returned by "to_dfa",
+/// runs starting from its "initial" state which is not given by the expression itself.
+/// Each call compiles and minimizes that DFA again so, to run several letters or traces,
+/// callers should rather compile the expression once with "to_dfa" and run the returned DFA.
+impl<Letter: AutLetter> AutRunnable<Letter> for ExpERE<Letter> {
+
+    fn runs_trace(&self, trace : &[Letter]) -> Result<bool,AutError<Letter>> {
+        if let Some(letter) = trace.iter().find(|letter| !self.alphabet.contains(letter)) {
+            return Err(AutError::UnknownLetter(*letter,self.alphabet.clone()));
+        }
+        self.to_dfa().runs_trace(trace)
+    }
+
+    fn run_transition(&self,
+                      active_states: &HashSet<usize>,
+                      letter: &Letter) ->  Result<HashSet<usize>,AutError<Letter>> {
+        if !self.alphabet.contains(letter) {
+            return Err(AutError::UnknownLetter(*letter,self.alphabet.clone()));
+        }
+        self.to_dfa().run_transition(active_states,letter)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::ere::ere::ExpERE;
+    use crate::parsers::ere_parser::parse_ere;
+    use crate::tests_utils::get_all_words;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn ere_run_transition_test() {
+        let alphabet = hashset!{'a','b'};
+        let ere = ExpERE::from_raw(alphabet.clone(),parse_ere("(ab|b){2,3}∩¬(.*bb.*)",&alphabet).unwrap()).unwrap();
+        // the states given by "run_transition" are those of the DFA returned by "to_dfa"
+        let dfa = ere.to_dfa();
+        for word in get_all_words(5) {
+            let mut active_states = hashset!{dfa.initial};
+            for letter in &word {
+                // a DFA has no run from an empty set of states
+                if active_states.is_empty() {
+                    break;
+                }
+                active_states = ere.run_transition(&active_states,letter).unwrap();
+            }
+            let accepted = active_states.iter().any(|state| dfa.finals.contains(state));
+            assert_eq!(accepted, ere.runs_trace(&word).unwrap());
+            assert_eq!(accepted, dfa.runs_trace(&word).unwrap());
+        }
+        assert!(ere.run_transition(&hashset!{dfa.initial},&'c').is_err());
+    }
+}
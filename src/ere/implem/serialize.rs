@@ -0,0 +1,114 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::ere::ere::ExpERE;
+use crate::ere::term::TermERE;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "term":term}
+/// where the term is serialized as per its derived implementation e.g. {"Concat":[{"Literal":"a"},"Epsilon"]}
+#[derive(Serialize, Deserialize)]
+struct ERELayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    term : TermERE<Letter>
+}
+
+/// Mirrors "TermERE" so that deserialized terms are checked (see "TermERE::check_repetitions")
+#[derive(Deserialize)]
+pub(crate) enum TermERELayout<Letter : AutLetter> {
+    Empty,
+    Epsilon,
+    Literal(Letter),
+    Union(BTreeSet<TermERE<Letter>>),
+    Concat(Vec<TermERE<Letter>>),
+    Repeat(Box<TermERE<Letter>>, usize, Option<usize>),
+    Intersection(BTreeSet<TermERE<Letter>>),
+    Negation(Box<TermERE<Letter>>),
+    Wildcard,
+}
+
+impl<Letter : AutLetter> TryFrom<TermERELayout<Letter>> for TermERE<Letter> {
+    type Error = AutError<Letter>;
+
+    fn try_from(layout : TermERELayout<Letter>) -> Result<Self, Self::Error> {
+        let term = match layout {
+            TermERELayout::Empty => TermERE::Empty,
+            TermERELayout::Epsilon => TermERE::Epsilon,
+            TermERELayout::Literal(letter) => TermERE::Literal(letter),
+            TermERELayout::Union(sub_terms) => TermERE::Union(sub_terms),
+            TermERELayout::Concat(sub_terms) => TermERE::Concat(sub_terms),
+            TermERELayout::Repeat(sub_term,min,max) => TermERE::Repeat(sub_term,min,max),
+            TermERELayout::Intersection(sub_terms) => TermERE::Intersection(sub_terms),
+            TermERELayout::Negation(sub_term) => TermERE::Negation(sub_term),
+            TermERELayout::Wildcard => TermERE::Wildcard
+        };
+        term.check_repetitions()?;
+        Ok(term)
+    }
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for ExpERE<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ERELayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            term : self.term.clone()
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for ExpERE<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = ERELayout::<Letter>::deserialize(deserializer)?;
+        ExpERE::from_raw(layout.alphabet.into_iter().collect(),layout.term).map_err(D::Error::custom)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::ere::ere::ExpERE;
+    use crate::ere::term::TermERE;
+    use crate::parsers::ere_parser::parse_ere;
+
+    #[test]
+    fn ere_serialization_test() {
+        let alphabet = hashset!{'a','b'};
+        let ere = ExpERE::from_raw(alphabet.clone(),parse_ere("(ab){2,3}∩¬(.*b)",&alphabet).unwrap()).unwrap();
+        let json = serde_json::to_string(&ere).unwrap();
+        let got : ExpERE<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.term, ere.term);
+        // a repetition whose minimum exceeds its maximum
+        let invalid = r#"{"alphabet":["a"],"term":{"Repeat":[{"Literal":"a"},3,1]}}"#;
+        assert!(serde_json::from_str::<ExpERE<char>>(invalid).is_err());
+        // terms are checked on their own, even when nested
+        let invalid = r#"{"Negation":{"Repeat":[{"Literal":"a"},3,1]}}"#;
+        assert!(serde_json::from_str::<TermERE<char>>(invalid).is_err());
+        let valid = r#"{"Negation":{"Repeat":[{"Literal":"a"},1,1]}}"#;
+        assert!(serde_json::from_str::<TermERE<char>>(valid).is_ok());
+        // a letter which is not in the alphabet
+        let invalid = r#"{"alphabet":["a"],"term":{"Literal":"b"}}"#;
+        assert!(serde_json::from_str::<ExpERE<char>>(invalid).is_err());
+    }
+}
@@ -15,11 +15,23 @@ limitations under the License.
 */
 
 
+use crate::ere::ere::ExpERE;
 use crate::ere::term::TermERE;
 use crate::traits::letter::AutLetter;
 use crate::traits::repr::{AbstractLanguagePrinter, ExpBREPrintable};
 
 
+impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for ExpERE<Letter> where
+    Letter : AutLetter,
+    Printer : AbstractLanguagePrinter<Letter> {
+
+    fn regexp_to_string(&self, use_html: bool, printer : &Printer) -> String {
+        self.term.regexp_to_string(use_html, printer)
+    }
+
+}
+
+
 impl<Letter : AutLetter> TermERE<Letter> {
 
     pub fn is_string_repr_atomic(&self, printer : &dyn AbstractLanguagePrinter<Letter>) -> bool {
@@ -32,7 +44,7 @@ impl<Letter : AutLetter> TermERE<Letter> {
                 match sub_terms.len() {
                     0 => { true },
                     1 => {
-                        sub_terms.get(0).unwrap().is_string_repr_atomic(printer)
+                        sub_terms.first().unwrap().is_string_repr_atomic(printer)
                     },
                     _ => { false }
                 }
@@ -72,6 +84,8 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermERE<Letter> where
             TermERE::Epsilon => {printer.get_epsilon_symbol(use_html).to_string()},
             TermERE::Literal(letter) => {printer.get_letter_string_repr(letter)},
             TermERE::Wildcard => {printer.get_wildcard_symbol(use_html).to_string()},
+            TermERE::Concat(sub_terms) if sub_terms.is_empty() => {printer.get_epsilon_symbol(use_html).to_string()},
+            TermERE::Union(sub_terms) if sub_terms.is_empty() => {printer.get_empty_symbol(use_html).to_string()},
             TermERE::Negation(sub_term) => {
                 let sub_term_as_string = <TermERE<Letter> as ExpBREPrintable<Letter, Printer>>::regexp_to_string(
                     sub_term, 
@@ -90,13 +104,17 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermERE<Letter> where
                         (<TermERE<Letter> as ExpBREPrintable<Letter, Printer>>::regexp_to_string(t, use_html,printer),
                          t.is_string_repr_atomic(printer)))
                     .collect();
-                sub_strs.iter().fold("".to_owned(),
-                                     |x,(repr,is_atomic)|
-                                         if *is_atomic {
-                                             x + printer.get_concatenation_separator(use_html) + repr
-                                         } else {
-                                             x + printer.get_concatenation_separator(use_html) + "(" + repr + ")"
-                                         })
+                // without separator, only alternations and intersections need parentheses
+                let implicit = printer.get_concatenation_separator(false).is_empty();
+                sub_strs.into_iter().zip(sub_terms.iter())
+                    .map(|((repr,is_atomic),t)|
+                        if is_atomic || (implicit && !matches!(t, TermERE::Union(_) | TermERE::Intersection(_))) {
+                            repr
+                        } else {
+                            format!("({})",repr)
+                        })
+                    .collect::<Vec<String>>()
+                    .join(printer.get_concatenation_separator(use_html))
             },
             TermERE::Union(sub_terms) => {
                 let sub_strs : Vec<(String,bool)> = sub_terms.iter()
@@ -104,13 +122,15 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermERE<Letter> where
                         (<TermERE<Letter> as ExpBREPrintable<Letter, Printer>>::regexp_to_string(t, use_html,printer),
                          t.is_string_repr_atomic(printer)))
                     .collect();
-                sub_strs.iter().fold("".to_owned(),
-                                     |x,(repr,is_atomic)|
-                                         if *is_atomic {
-                                             x + printer.get_alternation_separator(use_html) + repr
-                                         } else {
-                                             x + printer.get_alternation_separator(use_html) + "(" + repr + ")"
-                                         })
+                sub_strs.into_iter()
+                    .map(|(repr,is_atomic)|
+                        if is_atomic {
+                            repr
+                        } else {
+                            format!("({})",repr)
+                        })
+                    .collect::<Vec<String>>()
+                    .join(printer.get_alternation_separator(use_html))
             },
             TermERE::Intersection(sub_terms) => {
                 let sub_strs : Vec<(String,bool)> = sub_terms.iter()
@@ -118,13 +138,15 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermERE<Letter> where
                         (<TermERE<Letter> as ExpBREPrintable<Letter, Printer>>::regexp_to_string(t, use_html,printer),
                          t.is_string_repr_atomic(printer)))
                     .collect();
-                sub_strs.iter().fold("".to_owned(),
-                                     |x,(repr,is_atomic)|
-                                         if *is_atomic {
-                                             x + printer.get_intersection_separator(use_html) + repr
-                                         } else {
-                                             x + printer.get_intersection_separator(use_html) + "(" + repr + ")"
-                                         })
+                sub_strs.into_iter()
+                    .map(|(repr,is_atomic)|
+                        if is_atomic {
+                            repr
+                        } else {
+                            format!("({})",repr)
+                        })
+                    .collect::<Vec<String>>()
+                    .join(printer.get_intersection_separator(use_html))
             },
             TermERE::Repeat(sub_term, min, None) => {
                 let sub_term_as_string = <TermERE<Letter> as ExpBREPrintable<Letter, Printer>>::regexp_to_string(sub_term, use_html,printer);
@@ -184,3 +206,29 @@ impl<Letter, Printer> ExpBREPrintable<Letter, Printer> for TermERE<Letter> where
 }
 
 
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::ere::term::TermERE;
+    use crate::printers::p_chars::CharAsLetterPrinter;
+    use crate::traits::repr::ExpBREPrintable;
+
+    fn print(term : &TermERE<char>) -> String {
+        term.regexp_to_string(false,&CharAsLetterPrinter{})
+    }
+
+    #[test]
+    fn ere_separators_test() {
+        let a_or_b = TermERE::Union(BTreeSet::from([TermERE::Literal('a'),TermERE::Literal('b')]));
+        // separators are only put between sub-terms
+        assert_eq!(print(&a_or_b), "a|b");
+        assert_eq!(print(&TermERE::Intersection(BTreeSet::from([TermERE::Literal('a'),TermERE::Literal('b')]))), "a∩b");
+        assert_eq!(print(&TermERE::Concat(vec![a_or_b,TermERE::Literal('c')])), "(a|b)c");
+        // empty concatenations and unions
+        assert_eq!(print(&TermERE::Concat(vec![])), "𝜀");
+        assert_eq!(print(&TermERE::Union(BTreeSet::new())), "∅");
+    }
+}
@@ -0,0 +1,75 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::dfa::dfa::AutDFA;
+use crate::ere::algos::compilation::term_ere_to_dfa;
+use crate::ere::algos::conversion::{term_bre_to_term_ere, term_ere_to_term_bre};
+use crate::ere::ere::ExpERE;
+use crate::ere::term::TermERE;
+use crate::gnfa::gnfa::AutGNFA;
+use crate::nfa::nfa::AutNFA;
+use crate::nfait::nfait::AutNFAIT;
+
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::translate::AutTranslatable;
+
+
+impl<Letter : AutLetter> TermERE<Letter> {
+
+    /// Returns a BRE term expressing the same language over *alphabet* (see "term_ere_to_term_bre")
+    pub fn to_bre(&self, alphabet : &HashSet<Letter>) -> Result<TermBRE<Letter>,AutError<Letter>> {
+        term_ere_to_term_bre(self,alphabet)
+    }
+
+}
+
+impl<Letter : AutLetter> TermBRE<Letter> {
+
+    /// Returns a compact ERE term expressing the same language (see "term_bre_to_term_ere")
+    pub fn to_ere(&self) -> TermERE<Letter> {
+        term_bre_to_term_ere(self)
+    }
+
+}
+
+impl<Letter : AutLetter> AutTranslatable<Letter> for ExpERE<Letter> {
+
+    // compilation via the DFA layer ; the repetitions were checked by "from_raw"
+    fn to_dfa(&self) -> AutDFA<Letter> {
+        term_ere_to_dfa(&self.term,&self.alphabet).unwrap()
+    }
+
+    fn to_nfa(&self) -> AutNFA<Letter> {
+        self.to_dfa().to_nfa()
+    }
+
+    fn to_nfait(&self) -> AutNFAIT<Letter> {
+        self.to_dfa().to_nfait()
+    }
+
+    fn to_gnfa(&self) -> AutGNFA<Letter> {
+        self.to_bre().to_gnfa()
+    }
+
+    fn to_bre(&self) -> ExpBRE<Letter> {
+        ExpBRE::from_raw(self.alphabet.clone(),self.term.to_bre(&self.alphabet).unwrap()).unwrap()
+    }
+}
@@ -16,4 +16,6 @@ limitations under the License.
 
 
 pub mod term;
-mod implem;
\ No newline at end of file
+pub mod ere;
+pub mod algos;
+pub(crate) mod implem;
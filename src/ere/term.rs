@@ -15,11 +15,14 @@ limitations under the License.
 */
 
 use std::collections::{BTreeSet, HashSet};
-use maplit::hashset;
+use maplit::{btreeset, hashset};
 
+use crate::traits::error::AutError;
 use crate::traits::letter::AutLetter;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "crate::ere::implem::serialize::TermERELayout<Letter>"))]
 pub enum TermERE<Letter: AutLetter> {
     Empty,
     Epsilon,
@@ -66,33 +69,37 @@ impl<Letter: AutLetter> TermERE<Letter> {
         alphabet
     }
 
-    /*pub fn is_empty(&self) -> bool {
+    /// Returns the bounds of a repetition x{min,max} occurring in the term such that min > max, if any
+    pub fn find_invalid_repetition(&self) -> Option<(usize,usize)> {
         match self {
-            TermERE::Empty => true,
-            TermERE::Epsilon => false,
-            TermERE::Literal(_) => false,
-            TermERE::Wildcard => false,
-            TermERE::Union(sub_terms) => {
-                sub_terms.iter().all(|t| t.is_empty())
+            TermERE::Repeat(sub_term,min,max) => {
+                match max {
+                    Some(max) if max < min => Some((*min,*max)),
+                    _ => sub_term.find_invalid_repetition()
+                }
             },
-            TermERE::Concat(sub_terms) => {
-                sub_terms.iter().any(|t| t.is_empty())
+            TermERE::Union(sub_terms) | TermERE::Intersection(sub_terms) => {
+                sub_terms.iter().find_map(|t| t.find_invalid_repetition())
             },
-            TermERE::Intersection(sub_terms) => {
-                unimplemented!("to implement need to check intersection empty");
-                sub_terms.iter().any(|t| t.is_empty())
+            TermERE::Concat(sub_terms) => {
+                sub_terms.iter().find_map(|t| t.find_invalid_repetition())
             },
             TermERE::Negation(sub_term) => {
-                unimplemented!("to implement")
+                sub_term.find_invalid_repetition()
             },
-            TermERE::Repeat(sub_term,_, max) => {
-                match max {
-                    Some(0) => true,
-                    _ => sub_term.is_empty()
-                }
+            _ => None
+        }
+    }
+
+    /// Returns an error if a repetition x{min,max} occurring in the term is such that min > max (see "find_invalid_repetition")
+    pub fn check_repetitions(&self) -> Result<(),AutError<Letter>> {
+        match self.find_invalid_repetition() {
+            None => Ok(()),
+            Some((min,max)) => {
+                Err(AutError::Other(format!("invalid repetition bounds {{{},{}}} where the minimum exceeds the maximum", min, max)))
             }
         }
-    }*/
+    }
 
     pub fn expresses_epsilon(&self) -> bool {
         match self {
@@ -121,7 +128,81 @@ impl<Letter: AutLetter> TermERE<Letter> {
         }
     }
 
-}
+    pub fn unite(mut self, other : Self) -> Self {
+        self = match (self,other) {
+            (TermERE::Union(mut sub1), TermERE::Union(sub2)) => {
+                for t in sub2 {
+                    sub1.insert(t);
+                }
+                TermERE::Union(sub1)
+            },
+            (TermERE::Empty, t) => t,
+            (t, TermERE::Empty) => t,
+            (TermERE::Union(mut sub1), t) => {
+                sub1.insert(t);
+                TermERE::Union(sub1)
+            },
+            (t, TermERE::Union(mut sub2)) => {
+                sub2.insert(t);
+                TermERE::Union(sub2)
+            },
+            (t1, t2) => {
+                TermERE::Union(btreeset!{t1,t2})
+            }
+        };
+        self
+    }
 
+    pub fn concatenate(mut self, other: Self) -> Self {
+        self = match (self,other) {
+            (TermERE::Concat(mut sub1), TermERE::Concat(mut sub2)) => {
+                sub1.append(&mut sub2);
+                TermERE::Concat(sub1)
+            },
+            (TermERE::Epsilon, t) => t,
+            (t, TermERE::Epsilon) => t,
+            (TermERE::Empty, _) => TermERE::Empty,
+            (_, TermERE::Empty) => TermERE::Empty,
+            (TermERE::Concat(mut sub1), t) => {
+                sub1.push(t);
+                TermERE::Concat(sub1)
+            },
+            (t, TermERE::Concat(mut sub2)) => {
+                sub2.insert(0,t);
+                TermERE::Concat(sub2)
+            },
+            (t1, t2) => TermERE::Concat(vec![t1, t2]),
+        };
+        self
+    }
 
+    pub fn intersect(mut self, other : Self) -> Self {
+        self = match (self,other) {
+            (TermERE::Intersection(mut sub1), TermERE::Intersection(sub2)) => {
+                for t in sub2 {
+                    sub1.insert(t);
+                }
+                TermERE::Intersection(sub1)
+            },
+            (TermERE::Empty, _) => TermERE::Empty,
+            (_, TermERE::Empty) => TermERE::Empty,
+            (TermERE::Intersection(mut sub1), t) => {
+                sub1.insert(t);
+                TermERE::Intersection(sub1)
+            },
+            (t, TermERE::Intersection(mut sub2)) => {
+                sub2.insert(t);
+                TermERE::Intersection(sub2)
+            },
+            (t1, t2) => {
+                if t1 == t2 {
+                    t1
+                } else {
+                    TermERE::Intersection(btreeset!{t1,t2})
+                }
+            }
+        };
+        self
+    }
 
+}
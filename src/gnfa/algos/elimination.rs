@@ -0,0 +1,284 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::bre::term::TermBRE;
+use crate::gnfa::gnfa::AutGNFA;
+use crate::traits::access::AutAccessible;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// The order in which the intermediate states of a GNFA are ripped during state elimination.
+/// For the heuristics, ties are broken in favor of the state with the smallest identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum StateEliminationOrder {
+    /// At each step, rips the state of minimal weight in the sense of Delgado and Morais
+    /// i.e. the increase of the total size of the terms labelling the edges caused by ripping it
+    #[default]
+    DelgadoMorais,
+    /// At each step, rips the state which minimizes the product of its numbers of incoming and outgoing edges
+    FewestInOutEdges,
+    /// Rips the states in the given order, the remaining states being then ripped by increasing identifier
+    UserProvided(Vec<usize>)
+}
+
+/// Called after each elimination with the ripped state and the resulting GNFA
+pub type StateEliminationCallback<'a, Letter> = dyn FnMut(usize,&AutGNFA<Letter>) + 'a;
+
+/// Options for the translation of a GNFA into a regular expression via state elimination.
+/// If provided, *on_step* is called after each elimination with the ripped state and the resulting GNFA.
+/// The states of the intermediate GNFAs keep the identifiers they have in the original GNFA.
+pub struct StateEliminationOptions<'a, Letter : AutLetter> {
+    pub order : StateEliminationOrder,
+    pub on_step : Option<&'a mut StateEliminationCallback<'a, Letter>>
+}
+
+impl<Letter : AutLetter> Default for StateEliminationOptions<'_, Letter> {
+    fn default() -> Self {
+        StateEliminationOptions::new(StateEliminationOrder::default())
+    }
+}
+
+impl<'a, Letter : AutLetter> StateEliminationOptions<'a, Letter> {
+
+    pub fn new(order : StateEliminationOrder) -> Self {
+        StateEliminationOptions{order,on_step:None}
+    }
+
+    pub fn with_step_callback(mut self, on_step : &'a mut StateEliminationCallback<'a, Letter>) -> Self {
+        self.on_step = Some(on_step);
+        self
+    }
+
+}
+
+
+/// Returns a term expressing the language of *gnfa*, obtained by ripping its intermediate states one by one.
+/// States which are not both accessible and coaccessible are discarded beforehand.
+pub fn eliminate_states<Letter : AutLetter>(gnfa : &AutGNFA<Letter>,
+                                            options : StateEliminationOptions<Letter>) -> Result<TermBRE<Letter>,AutError<Letter>> {
+    let StateEliminationOptions{order,mut on_step} = options;
+    if let StateEliminationOrder::UserProvided(user_order) = &order {
+        let mut seen = BTreeSet::new();
+        for state in user_order {
+            if *state >= gnfa.states_num || *state == gnfa.start_state || *state == gnfa.accept_state {
+                return Err(AutError::Other(format!("cannot rip state {:} from GNFA", state)));
+            }
+            if !seen.insert(*state) {
+                return Err(AutError::Other(format!("state {:} occurs several times in the elimination order", state)));
+            }
+        }
+    }
+    // ***
+    let accessible = gnfa.get_all_accessible_states();
+    let coaccessible = gnfa.get_all_coaccessible_states();
+    let useful = |state : &usize| accessible.contains(state) && coaccessible.contains(state);
+    let mut to_rip : BTreeSet<usize> = (0..gnfa.states_num)
+        .filter(|state| *state != gnfa.start_state && *state != gnfa.accept_state && useful(state))
+        .collect();
+    let transitions : HashMap<(usize,usize),TermBRE<Letter>> = gnfa.get_transitions()
+        .filter(|((orig,targ),_)| useful(orig) && useful(targ))
+        .map(|(key,term)| (key,term.clone()))
+        .collect();
+    let mut current = AutGNFA::from_raw(gnfa.alphabet.clone(),
+                                        gnfa.states_num,
+                                        gnfa.start_state,
+                                        gnfa.accept_state,
+                                        transitions)?;
+    // ***
+    let mut user_order = match &order {
+        StateEliminationOrder::UserProvided(user_order) => user_order.iter().rev().cloned().collect(),
+        _ => vec![]
+    };
+    while !to_rip.is_empty() {
+        let ripped = match &order {
+            StateEliminationOrder::DelgadoMorais => {
+                select_by_min_key(&to_rip,|state| delgado_morais_weight(&current,state))
+            },
+            StateEliminationOrder::FewestInOutEdges => {
+                select_by_min_key(&to_rip,|state| {
+                    let neighborhood = get_neighborhood(&current,state);
+                    neighborhood.incoming.len() * neighborhood.outgoing.len()
+                })
+            },
+            StateEliminationOrder::UserProvided(_) => {
+                let mut ripped = None;
+                while let Some(state) = user_order.pop() {
+                    if to_rip.contains(&state) {
+                        ripped = Some(state);
+                        break;
+                    }
+                }
+                ripped.unwrap_or_else(|| *to_rip.iter().next().unwrap())
+            }
+        };
+        to_rip.remove(&ripped);
+        current.rip_state_in_place(ripped)?;
+        if let Some(callback) = on_step.as_mut() {
+            callback(ripped,&current);
+        }
+    }
+    // ***
+    Ok(current.get_transition(gnfa.start_state,gnfa.accept_state).cloned().unwrap_or(TermBRE::Empty))
+}
+
+fn select_by_min_key(candidates : &BTreeSet<usize>,
+                     key : impl Fn(usize) -> usize) -> usize {
+    // candidates are iterated by increasing identifier and the first minimum is kept
+    *candidates.iter().min_by_key(|state| key(**state)).unwrap()
+}
+
+/// The sizes of the terms labelling the non-empty edges which enter and exit a state (apart from its loop)
+/// and the size of the term labelling its loop if it is neither empty nor 𝜀
+struct Neighborhood {
+    incoming : Vec<usize>,
+    outgoing : Vec<usize>,
+    on_self : Option<usize>
+}
+
+fn get_neighborhood<Letter : AutLetter>(gnfa : &AutGNFA<Letter>,
+                                        state : usize) -> Neighborhood {
+    let mut incoming = vec![];
+    let mut outgoing = vec![];
+    let mut on_self = None;
+    for (orig,term) in gnfa.get_incoming_transitions(state) {
+        if orig != state {
+            incoming.push(term.size());
+        }
+    }
+    for (targ,term) in gnfa.get_outgoing_transitions(state) {
+        if targ == state {
+            if *term != TermBRE::Epsilon {
+                on_self = Some(term.size());
+            }
+        } else {
+            outgoing.push(term.size());
+        }
+    }
+    Neighborhood{incoming,outgoing,on_self}
+}
+
+/// The weight of Delgado and Morais i.e.
+/// sum(incoming) * (#outgoing - 1) + sum(outgoing) * (#incoming - 1) + loop * (#incoming * #outgoing - 1)
+fn delgado_morais_weight<Letter : AutLetter>(gnfa : &AutGNFA<Letter>,
+                                             state : usize) -> usize {
+    let Neighborhood{incoming,outgoing,on_self} = get_neighborhood(gnfa,state);
+    let (num_in,num_out) = (incoming.len() as i64, outgoing.len() as i64);
+    let sum_in = incoming.iter().sum::<usize>() as i64;
+    let sum_out = outgoing.iter().sum::<usize>() as i64;
+    let on_self = on_self.unwrap_or(0) as i64;
+    let weight = sum_in * (num_out - 1) + sum_out * (num_in - 1) + on_self * (num_in * num_out - 1);
+    // the weight is negative only for states with no incoming or no outgoing edge
+    weight.max(0) as usize
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::bre::term::TermBRE;
+    use crate::gnfa::algos::elimination::{StateEliminationOptions, StateEliminationOrder};
+    use crate::gnfa::gnfa::AutGNFA;
+    use crate::nfa::nfa::AutNFA;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    /// accepts the words over {a,b} in which the number of 'a' is a multiple of 3
+    fn get_example() -> AutNFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{1}, 'b' => hashset!{0}},
+            hashmap!{'a' => hashset!{2}, 'b' => hashset!{1}},
+            hashmap!{'a' => hashset!{0}, 'b' => hashset!{2}}
+        ];
+        AutNFA::<char>::from_raw(alphabet, hashset!{0}, hashset!{0}, transitions).unwrap()
+    }
+
+    #[test]
+    fn state_elimination_orders_test() {
+        let nfa = get_example();
+        let gnfa = nfa.to_gnfa();
+        let orders = [StateEliminationOrder::DelgadoMorais,
+                      StateEliminationOrder::FewestInOutEdges,
+                      StateEliminationOrder::UserProvided(vec![2,0])];
+        for order in orders {
+            let mut ripped = vec![];
+            let mut on_step = |state : usize, _ : &_| ripped.push(state);
+            let options = StateEliminationOptions::new(order.clone()).with_step_callback(&mut on_step);
+            let bre = gnfa.to_bre_with_options(options).unwrap();
+            if let StateEliminationOrder::UserProvided(_) = order {
+                assert_eq!(ripped, vec![2,0,1]);
+            } else {
+                assert_eq!(ripped.len(), 3);
+            }
+            // the output does not depend on the iteration order of hash maps
+            let again = gnfa.to_bre_with_options(StateEliminationOptions::new(order)).unwrap();
+            assert_eq!(bre.term, again.term);
+            for word in get_all_words(6) {
+                assert_eq!(bre.matches(&word), nfa.runs_trace(&word).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_elimination_order_test() {
+        let gnfa = get_example().to_gnfa();
+        // states 3 and 4 are the start and accept states
+        assert!(gnfa.to_bre_with_options(StateEliminationOptions::new(StateEliminationOrder::UserProvided(vec![3]))).is_err());
+        assert!(gnfa.to_bre_with_options(StateEliminationOptions::new(StateEliminationOrder::UserProvided(vec![1,1]))).is_err());
+    }
+
+    #[test]
+    fn sparse_elimination_test() {
+        // a chain of 300 states, each intermediate state having a loop labelled by 'b'
+        let states_num = 300;
+        let mut transitions = HashMap::new();
+        for state in 0..(states_num - 1) {
+            transitions.insert((state,state + 1),TermBRE::Literal('a'));
+            if state > 0 {
+                transitions.insert((state,state),TermBRE::Literal('b'));
+            }
+        }
+        let gnfa = AutGNFA::from_raw(hashset!{'a','b'},states_num,0,states_num - 1,transitions).unwrap();
+        // only the non-empty edges are stored
+        assert_eq!(gnfa.get_transitions().count(), 2 * states_num - 3);
+        assert_eq!(gnfa.get_transition(0,2), None);
+        // the dense view labels all the other possible edges
+        let dense = gnfa.transitions();
+        assert_eq!(dense.len(), (states_num - 1) * (states_num - 1));
+        assert_eq!(dense.get(&(0,2)), Some(&TermBRE::Empty));
+        assert_eq!(dense.get(&(1,1)), Some(&TermBRE::Literal('b')));
+        // there are no edges entering the start state or exiting the accept state
+        assert_eq!(dense.get(&(0,0)), None);
+        assert_eq!(dense.get(&(states_num - 1,1)), None);
+        // loops which are not given are labelled by 𝜀 in the dense view
+        let single = AutGNFA::from_raw(hashset!{'a'},3,0,2,hashmap!{(0,1) => TermBRE::Literal('a')}).unwrap();
+        assert_eq!(single.get_transition(1,1), None);
+        assert_eq!(single.transitions().get(&(1,1)), Some(&TermBRE::Epsilon));
+        let bre = gnfa.to_bre();
+        let mut word = vec!['a';states_num - 1];
+        assert!(bre.matches(&word));
+        word.insert(1,'b');
+        assert!(bre.matches(&word));
+        word.pop();
+        assert!(!bre.matches(&word));
+    }
+}
@@ -0,0 +1,150 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use crate::bre::term::TermBRE;
+use crate::gnfa::gnfa::AutGNFA;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// A configuration of a run of a GNFA.
+/// Either the run is at a state, or it is reading the edge from *orig* to *targ*
+/// and *residual* is the derivative of the term labelling the edge w.r.t. the letters read on that edge so far
+/// i.e. it expresses what remains to be read before reaching *targ*.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GnfaConfiguration<Letter : AutLetter> {
+    AtState(usize),
+    WithinEdge(usize,usize,TermBRE<Letter>)
+}
+
+/// Returns the configurations in which a run of *gnfa* can be before reading any letter
+pub fn get_initial_configurations<Letter : AutLetter>(gnfa : &AutGNFA<Letter>) -> BTreeSet<GnfaConfiguration<Letter>> {
+    get_configurations_closure(gnfa,BTreeSet::from([GnfaConfiguration::AtState(gnfa.start_state)]))
+}
+
+/// Saturates *configurations* with the moves that do not read any letter:
+/// from a state, entering any of its non-empty outgoing edges,
+/// and from within an edge whose residual expresses 𝜀, reaching the target of the edge
+pub fn get_configurations_closure<Letter : AutLetter>(gnfa : &AutGNFA<Letter>,
+                                                      configurations : BTreeSet<GnfaConfiguration<Letter>>) -> BTreeSet<GnfaConfiguration<Letter>> {
+    let mut closure = BTreeSet::new();
+    let mut stack : Vec<GnfaConfiguration<Letter>> = configurations.into_iter().collect();
+    while let Some(configuration) = stack.pop() {
+        if closure.contains(&configuration) {
+            continue;
+        }
+        match &configuration {
+            GnfaConfiguration::AtState(state) => {
+                if *state < gnfa.states_num {
+                    for (targ,term) in gnfa.get_outgoing_transitions(*state) {
+                        stack.push(GnfaConfiguration::WithinEdge(*state,targ,term.clone()));
+                    }
+                }
+            },
+            GnfaConfiguration::WithinEdge(_,targ,residual) => {
+                if residual.expresses_epsilon() {
+                    stack.push(GnfaConfiguration::AtState(*targ));
+                }
+            }
+        }
+        closure.insert(configuration);
+    }
+    closure
+}
+
+/// Returns the configurations reached from *configurations* by reading *letter* within an edge,
+/// saturated with the moves that do not read any letter
+pub fn run_configurations<Letter : AutLetter>(gnfa : &AutGNFA<Letter>,
+                                              configurations : &BTreeSet<GnfaConfiguration<Letter>>,
+                                              letter : &Letter) -> Result<BTreeSet<GnfaConfiguration<Letter>>,AutError<Letter>> {
+    let mut next = BTreeSet::new();
+    for configuration in get_configurations_closure(gnfa,configurations.clone()) {
+        match configuration {
+            GnfaConfiguration::AtState(state) => {
+                if state >= gnfa.states_num {
+                    return Err(AutError::InvalidStateToRun(state,gnfa.states_num));
+                }
+            },
+            GnfaConfiguration::WithinEdge(orig,targ,residual) => {
+                let derivative = residual.derivative(letter);
+                if !derivative.is_empty() {
+                    next.insert(GnfaConfiguration::WithinEdge(orig,targ,derivative));
+                }
+            }
+        }
+    }
+    Ok(get_configurations_closure(gnfa,next))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use graphviz_dot_builder::edge::style::GraphvizEdgeStyleItem;
+    use maplit::hashset;
+    use crate::gnfa::algos::execution::GnfaConfiguration;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::printers::p_chars::CharAsLetterPrinter;
+    use crate::traits::build::AutBuildable;
+    use crate::traits::error::AutError;
+    use crate::traits::repr::AUT_COLOR_ACTIVE_STATE;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    #[test]
+    fn gnfa_execution_test() {
+        let alphabet = hashset!{'a','b'};
+        let left = parse_bre("(ab|b)*",&alphabet).unwrap();
+        let right = parse_bre("a(ba)*",&alphabet).unwrap();
+        let expected = left.clone().concatenate(right.clone()).unwrap().kleene();
+        let gnfa = left.to_gnfa().concatenate(right.to_gnfa()).unwrap().kleene();
+        for word in get_all_words(7) {
+            assert_eq!(gnfa.runs_trace(&word).unwrap(), expected.matches(&word));
+        }
+        // reading 'a' from the start of the edge labelled by (ab|b)* leads within that edge
+        let single = left.to_gnfa();
+        let configurations = single.run_configurations(&single.get_initial_configurations(),&'a').unwrap();
+        let residual = parse_bre("b(ab|b)*",&alphabet).unwrap().term;
+        assert_eq!(configurations, BTreeSet::from([GnfaConfiguration::WithinEdge(0,1,residual)]));
+        // runs within an edge which may still read letters on it are not dropped by "run_transition"
+        assert!(matches!(single.run_transition(&hashset!{0},&'a'), Err(AutError::Other(_))));
+        assert!(matches!(single.run_transition(&hashset!{0},&'b'), Err(AutError::Other(_))));
+        // the active edge is highlighted
+        let digraph = single.to_dot_with_configurations(false,&configurations,&CharAsLetterPrinter{});
+        let active_edges = digraph.edges.iter()
+            .filter(|edge| edge.style.contains(&GraphvizEdgeStyleItem::Color(AUT_COLOR_ACTIVE_STATE)))
+            .count();
+        assert_eq!(active_edges, 1);
+    }
+
+    #[test]
+    fn gnfa_run_transition_test() {
+        // the edges of a GNFA obtained from a NFA are labelled by letters or 𝜀 so runs never stay within them
+        let expected = parse_bre("(ab|b)*a",&hashset!{'a','b'}).unwrap();
+        let gnfa = expected.to_nfa().to_gnfa();
+        for word in get_all_words(6).into_iter().filter(|word| !word.is_empty()) {
+            let mut active_states = hashset!{gnfa.start_state};
+            for letter in &word {
+                active_states = gnfa.run_transition(&active_states,letter).unwrap();
+            }
+            assert_eq!(active_states.contains(&gnfa.accept_state), expected.matches(&word));
+        }
+    }
+}
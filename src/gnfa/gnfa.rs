@@ -14,8 +14,8 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::{HashMap, HashSet};
-use maplit::{hashset,hashmap};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use crate::bre::bre::ExpBRE;
 use crate::bre::term::TermBRE;
@@ -24,19 +24,105 @@ use crate::traits::letter::AutLetter;
 use crate::traits::error::AutError;
 
 
+/// Transitions are stored sparsely : only the edges labelled by non-empty terms are represented
+/// and all the other edges are implicitly labelled by ∅.
+/// In particular, the loops which are not explicitly given are labelled by ∅ rather than 𝜀,
+/// which does not change the language as ∅* = 𝜀* = 𝜀 (see "transitions" for a dense view).
 #[derive(Debug, Clone)]
 pub struct AutGNFA<Letter : AutLetter> {
     pub alphabet: HashSet<Letter>,
     pub states_num : usize,
     pub start_state : usize,
     pub accept_state : usize,
-    pub transitions: HashMap<(usize,usize), TermBRE<Letter>>
+    /// for each state, the terms labelling its outgoing edges, indexed by their targets
+    outgoing : Vec<BTreeMap<usize,TermBRE<Letter>>>,
+    /// for each state, the origins of its incoming edges
+    incoming : Vec<BTreeSet<usize>>
 }
 
 
 impl<Letter: AutLetter> AutGNFA<Letter> {
 
+    /// Returns a dense view of the transitions in which every possible edge is labelled :
+    /// all pairs of states (i,j) with i distinct from the accept state and j distinct from the start state are keys,
+    /// missing edges being labelled by ∅ and missing loops by 𝜀.
+    /// Building this view is quadratic in the number of states.
+    pub fn transitions(&self) -> HashMap<(usize,usize), TermBRE<Letter>> {
+        let mut transitions = HashMap::new();
+        for orig in (0..self.states_num).filter(|state| *state != self.accept_state) {
+            for targ in (0..self.states_num).filter(|state| *state != self.start_state) {
+                let term = match self.get_transition(orig,targ) {
+                    Some(term) => term.clone(),
+                    None if orig == targ => TermBRE::Epsilon,
+                    None => TermBRE::Empty
+                };
+                transitions.insert((orig,targ),term);
+            }
+        }
+        transitions
+    }
+
+    /// Returns the term labelling the edge from *orig* to *targ* or None if it is implicitly labelled by ∅
+    pub fn get_transition(&self, orig : usize, targ : usize) -> Option<&TermBRE<Letter>> {
+        self.outgoing.get(orig).and_then(|edges| edges.get(&targ))
+    }
+
+    /// Returns the non-empty edges exiting *orig*, ordered by target
+    pub fn get_outgoing_transitions(&self, orig : usize) -> impl Iterator<Item=(usize,&TermBRE<Letter>)> + '_ {
+        self.outgoing[orig].iter().map(|(targ,term)| (*targ,term))
+    }
+
+    /// Returns the non-empty edges entering *targ*, ordered by origin
+    pub fn get_incoming_transitions(&self, targ : usize) -> impl Iterator<Item=(usize,&TermBRE<Letter>)> + '_ {
+        self.incoming[targ].iter().map(move |orig| (*orig,self.outgoing[*orig].get(&targ).unwrap()))
+    }
+
+    /// Returns all the non-empty edges, ordered by origin and then by target
+    pub fn get_transitions(&self) -> impl Iterator<Item=((usize,usize),&TermBRE<Letter>)> + '_ {
+        self.outgoing.iter().enumerate()
+            .flat_map(|(orig,edges)| edges.iter().map(move |(targ,term)| ((orig,*targ),term)))
+    }
+
+    /// Labels the edge from *orig* to *targ* by *term*, which removes the edge if *term* is empty
+    pub fn set_transition(&mut self,
+                          orig : usize,
+                          targ : usize,
+                          term : TermBRE<Letter>) -> Result<(),AutError<Letter>> {
+        if orig >= self.states_num || targ >= self.states_num {
+            return Err(AutError::Other(format!("transition from {:} to {:} not in set of states {:?}", orig, targ, 0..self.states_num)));
+        }
+        if orig == self.accept_state {
+            return Err(AutError::Other(format!("should not have outgoing transitions from GNFA accept state {:}", orig)));
+        }
+        if targ == self.start_state {
+            return Err(AutError::Other(format!("should not have incoming transitions towards GNFA start state {:}", targ)));
+        }
+        let checked = ExpBRE::from_raw(self.alphabet.clone(),term)?;
+        self.set_edge(orig,targ,checked.term);
+        Ok(())
+    }
+
+    fn set_edge(&mut self, orig : usize, targ : usize, term : TermBRE<Letter>) {
+        if term.is_empty() {
+            self.outgoing[orig].remove(&targ);
+            self.incoming[targ].remove(&orig);
+        } else {
+            self.outgoing[orig].insert(targ,term);
+            self.incoming[targ].insert(orig);
+        }
+    }
+
     pub fn rip_state(&self, to_rip_id : usize) -> Result<Self,AutError<Letter>> {
+        let mut ripped = self.clone();
+        ripped.rip_state_in_place(to_rip_id)?;
+        Ok(ripped)
+    }
+
+    /// Removes all the edges entering and exiting the state *to_rip_id*
+    /// and, for each pair of an incoming edge labelled by *x* and an outgoing edge labelled by *y*, adds x.l*.y to the edge between their extremities,
+    /// where *l* labels the loop on *to_rip_id*.
+    /// The numbering of the states is unchanged and the cost is proportional to the number of such pairs.
+    pub fn rip_state_in_place(&mut self, to_rip_id : usize) -> Result<(),AutError<Letter>> {
         if self.states_num <= 2 {
             return Err(AutError::Other("cannot rip any more states in GNFA".to_string()));
         }
@@ -46,57 +132,42 @@ impl<Letter: AutLetter> AutGNFA<Letter> {
         if to_rip_id == self.accept_state {
             return Err(AutError::Other("cannot rip accept state from GNFA".to_string()));
         }
-        // ***
-        let mut incoming : HashSet<(usize,TermBRE<Letter>)> = hashset!{};
-        let mut outgoing : HashSet<(usize,TermBRE<Letter>)> = hashset!{};
-        let mut on_self : Option<TermBRE<Letter>> = None;
-        for ((orig,targ),term) in &self.transitions {
-            if orig == targ {
-                if *orig == to_rip_id && !term.is_empty() {
-                    on_self = Some(term.clone());
-                }
-            } else {
-                if *targ == to_rip_id {
-                    incoming.insert((*orig,term.clone()));
-                }
-                if *orig == to_rip_id {
-                    outgoing.insert((*targ,term.clone()));
-                }
-            }
+        if to_rip_id >= self.states_num {
+            return Err(AutError::Other(format!("cannot rip state {:} which is not in GNFA", to_rip_id)));
         }
         // ***
-        let mut new_transitions : HashMap<(usize,usize), TermBRE<Letter>> = hashmap!{};
-        for ((orig,targ),term) in &self.transitions {
-            if *orig != to_rip_id && *targ != to_rip_id {
-                new_transitions.insert((*orig,*targ), term.clone());
+        let mut outgoing = std::mem::take(&mut self.outgoing[to_rip_id]);
+        let incoming = std::mem::take(&mut self.incoming[to_rip_id]);
+        let middle : TermBRE<Letter> = match outgoing.remove(&to_rip_id) {
+            None | Some(TermBRE::Epsilon) => {
+                TermBRE::Epsilon
+            },
+            Some(TermBRE::Kleene(t)) => {
+                TermBRE::Kleene(t)
+            },
+            Some(t) => {
+                TermBRE::Kleene(Box::new(t))
             }
+        };
+        for targ_id in outgoing.keys() {
+            self.incoming[*targ_id].remove(&to_rip_id);
         }
         // ***
-        for (orig_id,orig_term) in &incoming {
-            for (targ_id,targ_tem) in &outgoing {
-                let middle : TermBRE<Letter> =
-                match &on_self {
-                    None => {
-                        TermBRE::Epsilon
-                    },
-                    Some(t) => {
-                        t.clone()
-                    }
-                };
-                //let new_tr_term = TermBRE::Concat(vec![orig_term.clone(),middle,targ_tem.clone()]);
-                let mut new_tr_term = orig_term.clone().concatenate(middle);
-                new_tr_term = new_tr_term.concatenate(targ_tem.clone());
-                let old_tr_term = self.transitions.get(&(*orig_id,*targ_id)).unwrap();
-                new_tr_term = new_tr_term.unite(old_tr_term.clone());
-                new_transitions.insert((*orig_id,*targ_id), new_tr_term);
+        for orig_id in incoming {
+            if orig_id == to_rip_id {
+                continue;
+            }
+            let orig_term = self.outgoing[orig_id].remove(&to_rip_id).unwrap();
+            for (targ_id,targ_term) in &outgoing {
+                let mut new_tr_term = orig_term.clone().concatenate(middle.clone());
+                new_tr_term = new_tr_term.concatenate(targ_term.clone());
+                if let Some(old_tr_term) = self.outgoing[orig_id].get(targ_id) {
+                    new_tr_term = new_tr_term.unite(old_tr_term.clone());
+                }
+                self.set_edge(orig_id,*targ_id,new_tr_term);
             }
         }
-        // ***
-        Self::from_raw(self.alphabet.clone(),
-                       self.states_num,
-                       self.start_state,
-                       self.accept_state,
-                       new_transitions)
+        Ok(())
     }
 
     pub fn from_raw(
@@ -112,44 +183,45 @@ impl<Letter: AutLetter> AutGNFA<Letter> {
         if accept_state >= states_num {
             return Err(AutError::InvalidFinal(accept_state, states_num));
         }
-        let mut transitions = hashmap!{};
-        for i in 0..states_num {
-            for j in 0..states_num {
-                match raw_transitions.get(&(i,j)) {
-                    None => {
-                        if i != accept_state && j != start_state {
-                            if i != j {
-                                transitions.insert( (i,j),
-                                                    TermBRE::Empty);
-                            } else {
-                                transitions.insert( (i,j),
-                                                    TermBRE::Epsilon);
-                            }
-                        }
-                    },
-                    Some( op) => {
-                        if i == accept_state {
-                            return Err(AutError::Other(format!("should not have outgoing transitions from GNFA accept state {:}", i)));
-                        }
-                        if j == start_state {
-                            return Err(AutError::Other(format!("should not have incoming transitions towards GNFA start state {:}", j)));
-                        }
-                        match ExpBRE::from_raw(alphabet.clone(),op.clone()) {
-                            Err(e) => {
-                                return Err(e);
-                            },
-                            Ok( got) => {
-                                transitions.insert( (i,j), got.term);
-                            }
-                        }
-                    }
-                }
-            }
+        let mut gnfa = Self{alphabet,
+            states_num,
+            start_state,
+            accept_state,
+            outgoing : vec![BTreeMap::new();states_num],
+            incoming : vec![BTreeSet::new();states_num]};
+        for ((orig,targ),term) in raw_transitions {
+            gnfa.set_transition(orig,targ,term)?;
         }
         // ***
-        Ok(Self{alphabet,states_num,start_state,accept_state,transitions})
+        Ok(gnfa)
     }
 }
 
 
 
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+    use crate::bre::term::TermBRE;
+    use crate::gnfa::gnfa::AutGNFA;
+
+    #[test]
+    fn rip_state_self_loop_test() {
+        let a = TermBRE::Literal('a');
+        let b_star = TermBRE::Kleene(Box::new(TermBRE::Literal('b')));
+        // the loop on the ripped state is starred unless it already is a Kleene star or 𝜀
+        for (self_loop,middle) in [(TermBRE::Literal('b'),Some(b_star.clone())),
+                                   (b_star.clone(),Some(b_star.clone())),
+                                   (TermBRE::Epsilon,None)] {
+            let transitions = hashmap!{(0,1) => a.clone(), (1,1) => self_loop, (1,2) => a.clone()};
+            let gnfa = AutGNFA::from_raw(hashset!{'a','b'},3,0,2,transitions).unwrap();
+            let ripped = gnfa.rip_state(1).unwrap();
+            let expected = match middle {
+                None => TermBRE::Concat(vec![a.clone(),a.clone()]),
+                Some(middle) => TermBRE::Concat(vec![a.clone(),middle,a.clone()])
+            };
+            assert_eq!(ripped.get_transition(0,2), Some(&expected));
+            assert_eq!(ripped.get_transitions().count(), 1);
+        }
+    }
+}
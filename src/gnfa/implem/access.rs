@@ -34,12 +34,10 @@ impl<Letter: AutLetter> AutAccessible for AutGNFA<Letter> {
         let mut set_of_accessible_states: HashSet<usize> = hashset!{self.start_state};
         let mut stack: Vec<usize> = vec![self.start_state];
         while let Some(origin_state) = stack.pop() {
-            for target_state in 0..self.states_num {
-                if let Some(regexp) = self.transitions.get(&(origin_state,target_state)) {
-                    if !regexp.is_empty() && !set_of_accessible_states.contains(&target_state) {
-                        set_of_accessible_states.insert(target_state);
-                        stack.push(target_state);
-                    }
+            for (target_state,_) in self.get_outgoing_transitions(origin_state) {
+                if !set_of_accessible_states.contains(&target_state) {
+                    set_of_accessible_states.insert(target_state);
+                    stack.push(target_state);
                 }
             }
         }
@@ -56,24 +54,20 @@ impl<Letter: AutLetter> AutAccessible for AutGNFA<Letter> {
         while !stack.is_empty() {
             let orig_in_old = stack.pop().unwrap();
             let orig_in_new : usize = *states_map.get(&orig_in_old).unwrap();
-            for targ_in_old in 0..self.states_num {
-                if let Some(term) = self.transitions.get(&(orig_in_old,targ_in_old)) {
-                    if !term.is_empty() {
-                        let targ_in_new = match states_map.get(&targ_in_old) {
-                            None => {
-                                let targ_in_new = new_states_num;
-                                new_states_num += 1;
-                                states_map.insert(targ_in_old,targ_in_new);
-                                stack.push(targ_in_old);
-                                targ_in_new
-                            },
-                            Some(targ_in_new) => {
-                                *targ_in_new
-                            }
-                        };
-                        new_transitions.insert((orig_in_new,targ_in_new),term.clone());
+            for (targ_in_old,term) in self.get_outgoing_transitions(orig_in_old) {
+                let targ_in_new = match states_map.get(&targ_in_old) {
+                    None => {
+                        let targ_in_new = new_states_num;
+                        new_states_num += 1;
+                        states_map.insert(targ_in_old,targ_in_new);
+                        stack.push(targ_in_old);
+                        targ_in_new
+                    },
+                    Some(targ_in_new) => {
+                        *targ_in_new
                     }
-                }
+                };
+                new_transitions.insert((orig_in_new,targ_in_new),term.clone());
             }
         }
         Self::from_raw(self.alphabet,new_states_num,new_start_state,new_accept_state,new_transitions).unwrap()
@@ -87,12 +81,10 @@ impl<Letter: AutLetter> AutAccessible for AutGNFA<Letter> {
         let mut set_of_coaccessible_states: HashSet<usize> = hashset!{self.accept_state};
         let mut stack: Vec<usize> = vec![self.accept_state];
         while let Some(target_state) = stack.pop() {
-            for origin_state in 0..self.states_num {
-                if let Some(regexp) = self.transitions.get(&(origin_state,target_state)) {
-                    if !regexp.is_empty() && !set_of_coaccessible_states.contains(&origin_state) {
-                        set_of_coaccessible_states.insert(origin_state);
-                        stack.push(origin_state);
-                    }
+            for (origin_state,_) in self.get_incoming_transitions(target_state) {
+                if !set_of_coaccessible_states.contains(&origin_state) {
+                    set_of_coaccessible_states.insert(origin_state);
+                    stack.push(origin_state);
                 }
             }
         }
@@ -109,24 +101,20 @@ impl<Letter: AutLetter> AutAccessible for AutGNFA<Letter> {
         while !stack.is_empty() {
             let targ_in_old = stack.pop().unwrap();
             let targ_in_new : usize = *states_map.get(&targ_in_old).unwrap();
-            for orig_in_old in 0..self.states_num {
-                if let Some(term) = self.transitions.get(&(orig_in_old,targ_in_old)) {
-                    if !term.is_empty() {
-                        let orig_in_new = match states_map.get(&orig_in_old) {
-                            None => {
-                                let orig_in_new = new_states_num;
-                                new_states_num += 1;
-                                states_map.insert(orig_in_old,orig_in_new);
-                                stack.push(orig_in_old);
-                                orig_in_new
-                            },
-                            Some(orig_in_new) => {
-                                *orig_in_new
-                            }
-                        };
-                        new_transitions.insert((orig_in_new,targ_in_new),term.clone());
+            for (orig_in_old,term) in self.get_incoming_transitions(targ_in_old) {
+                let orig_in_new = match states_map.get(&orig_in_old) {
+                    None => {
+                        let orig_in_new = new_states_num;
+                        new_states_num += 1;
+                        states_map.insert(orig_in_old,orig_in_new);
+                        stack.push(orig_in_old);
+                        orig_in_new
+                    },
+                    Some(orig_in_new) => {
+                        *orig_in_new
                     }
-                }
+                };
+                new_transitions.insert((orig_in_new,targ_in_new),term.clone());
             }
         }
         Self::from_raw(self.alphabet,new_states_num,new_start_state,new_accept_state,new_transitions).unwrap()
@@ -140,4 +128,64 @@ impl<Letter: AutLetter> AutAccessible for AutGNFA<Letter> {
         self.make_coaccessible().make_accessible()
     }
 
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+    use crate::bre::term::TermBRE;
+    use crate::gnfa::gnfa::AutGNFA;
+    use crate::random::params::RandomAutomatonParameters;
+    use crate::traits::access::AutAccessible;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::for_each_random_nfait;
+
+    #[test]
+    fn gnfa_trim_test() {
+        // a.b*.a with an inaccessible state 3 and a dead state 4
+        let transitions = hashmap!{
+            (0,2) => TermBRE::Literal('a'),
+            (2,2) => TermBRE::Literal('b'),
+            (2,1) => TermBRE::Literal('a'),
+            (3,2) => TermBRE::Literal('b'),
+            (0,4) => TermBRE::Literal('b'),
+            (4,4) => TermBRE::Literal('a')
+        };
+        let gnfa = AutGNFA::from_raw(hashset!{'a','b'},5,0,1,transitions).unwrap();
+        assert_eq!(gnfa.get_all_accessible_states(), hashset!{0,1,2,4});
+        assert_eq!(gnfa.get_all_coaccessible_states(), hashset!{0,1,2,3});
+        // ***
+        let accessible = gnfa.clone().make_accessible();
+        assert_eq!(accessible.states_num, 4);
+        assert!(accessible.is_accessible());
+        assert!(accessible.to_dfa().equals(&gnfa.to_dfa()));
+        let coaccessible = gnfa.clone().make_coaccessible();
+        assert_eq!(coaccessible.states_num, 4);
+        assert!(coaccessible.is_coaccessible());
+        assert!(coaccessible.to_dfa().equals(&gnfa.to_dfa()));
+        // ***
+        let trimmed = gnfa.clone().trim();
+        assert_eq!(trimmed.states_num, 3);
+        assert!(trimmed.is_trimmed());
+        assert!(trimmed.to_dfa().equals(&gnfa.to_dfa()));
+        assert!(trimmed.get_incoming_transitions(trimmed.start_state).next().is_none());
+        assert!(trimmed.get_outgoing_transitions(trimmed.accept_state).next().is_none());
+    }
+
+    #[test]
+    fn gnfa_random_trim_test() {
+        let mut params = RandomAutomatonParameters::new(hashset!{'a','b'},6);
+        params.transition_density = 0.2;
+        params.epsilon_density = 0.1;
+        for_each_random_nfait(13,20,&params,|nfait| {
+            let gnfa = nfait.to_gnfa();
+            let trimmed = gnfa.clone().trim();
+            assert!(trimmed.to_dfa().equals(&gnfa.to_dfa()));
+            if !gnfa.is_empty() {
+                assert!(trimmed.is_trimmed());
+            }
+        });
+    }
+}
@@ -14,42 +14,224 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
+use std::collections::{HashMap, HashSet};
 use std::ops::RangeBounds;
+use std::ops::Bound::{Included,Excluded,Unbounded};
+use maplit::hashmap;
 
+use crate::bre::term::TermBRE;
 use crate::gnfa::gnfa::AutGNFA;
 use crate::traits::letter::AutLetter;
 use crate::traits::build::AutBuildable;
 use crate::traits::error::AutError;
 
+
+impl<Letter: AutLetter> AutGNFA<Letter> {
+
+    /// Returns a GNFA with only a start state and an accept state linked by an edge labelled by *term*
+    fn new_single_edge(alphabet : HashSet<Letter>, term : TermBRE<Letter>) -> Self {
+        AutGNFA::from_raw(alphabet,2,0,1,hashmap!{(0,1) => term}).unwrap()
+    }
+
+    /// Copies the non-empty edges of the GNFA into *transitions* with the states renamed via *rename*,
+    /// edges which end up between the same states being united
+    fn copy_edges_into(&self,
+                       transitions : &mut HashMap<(usize,usize),TermBRE<Letter>>,
+                       rename : &dyn Fn(usize) -> usize) {
+        for ((orig,targ),term) in self.get_transitions() {
+            let key = (rename(orig),rename(targ));
+            let new_term = match transitions.remove(&key) {
+                None => term.clone(),
+                Some(previous) => previous.unite(term.clone())
+            };
+            transitions.insert(key,new_term);
+        }
+    }
+
+    /// Returns the identifiers given to the states of the GNFA other than *merged*
+    /// when they are appended after *offset* states
+    fn shift_states_except(&self, merged : &[usize], offset : usize) -> HashMap<usize,usize> {
+        (0..self.states_num)
+            .filter(|state| !merged.contains(state))
+            .enumerate()
+            .map(|(index,state)| (state,offset + index))
+            .collect()
+    }
+
+}
+
 impl<Letter: AutLetter> AutBuildable<Letter> for AutGNFA<Letter> {
 
-    fn unite(mut self, other: Self) -> Result<Self,AutError<Letter>> {
-        unimplemented!();
+    // the start states (resp. accept states) have no incoming (resp. outgoing) edges so they can be merged
+    fn unite(self, other: Self) -> Result<Self,AutError<Letter>> {
+        if self.alphabet != other.alphabet {
+            return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(self.alphabet,
+                                                                            other.alphabet));
+        }
+        let shifted = other.shift_states_except(&[other.start_state,other.accept_state],self.states_num);
+        let rename = |state : usize| {
+            if state == other.start_state {
+                self.start_state
+            } else if state == other.accept_state {
+                self.accept_state
+            } else {
+                *shifted.get(&state).unwrap()
+            }
+        };
+        let mut transitions = hashmap!{};
+        self.copy_edges_into(&mut transitions,&|state| state);
+        other.copy_edges_into(&mut transitions,&rename);
+        AutGNFA::from_raw(self.alphabet,
+                          self.states_num + shifted.len(),
+                          self.start_state,
+                          self.accept_state,
+                          transitions)
     }
 
-    fn concatenate(mut self, mut other: Self) -> Result<Self,AutError<Letter>> {
-        unimplemented!();
+    // the accept state of self is merged with the start state of other
+    fn concatenate(self, other: Self) -> Result<Self,AutError<Letter>> {
+        if self.alphabet != other.alphabet {
+            return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(self.alphabet,
+                                                                            other.alphabet));
+        }
+        let shifted = other.shift_states_except(&[other.start_state],self.states_num);
+        let rename = |state : usize| {
+            if state == other.start_state {
+                self.accept_state
+            } else {
+                *shifted.get(&state).unwrap()
+            }
+        };
+        let mut transitions = hashmap!{};
+        self.copy_edges_into(&mut transitions,&|state| state);
+        other.copy_edges_into(&mut transitions,&rename);
+        AutGNFA::from_raw(self.alphabet,
+                          self.states_num + shifted.len(),
+                          self.start_state,
+                          rename(other.accept_state),
+                          transitions)
     }
 
     fn repeat(self, num : usize) -> Self {
-        unimplemented!();
+        (0..num).fold(AutGNFA::new_single_edge(self.alphabet.clone(),TermBRE::Epsilon),
+                      |acc,_| acc.concatenate(self.clone()).unwrap())
     }
 
-    fn kleene(mut self) -> Self {
-        unimplemented!();
+    // the former accept state is merged with the former start state which becomes an intermediate state
+    // linked to a new start state and to a new accept state (which takes the identifier of the former accept state)
+    fn kleene(self) -> Self {
+        let loop_state = self.start_state;
+        let new_start = self.states_num;
+        let new_accept = self.accept_state;
+        let mut transitions = hashmap!{};
+        self.copy_edges_into(&mut transitions,&|state| if state == self.accept_state {loop_state} else {state});
+        transitions.insert((new_start,loop_state),TermBRE::Epsilon);
+        transitions.insert((loop_state,new_accept),TermBRE::Epsilon);
+        transitions.insert((new_start,new_accept),TermBRE::Epsilon);
+        AutGNFA::from_raw(self.alphabet,
+                          self.states_num + 1,
+                          new_start,
+                          new_accept,
+                          transitions).unwrap()
     }
 
-    fn at_most(mut self, num: usize) -> Self {
-        unimplemented!();
+    fn at_most(self, num: usize) -> Self {
+        let epsilon = AutGNFA::new_single_edge(self.alphabet.clone(),TermBRE::Epsilon);
+        self.unite(epsilon).unwrap().repeat(num)
     }
 
     fn at_least(self, num: usize) -> Self {
-        unimplemented!();
+        self.clone().repeat(num).concatenate(self.kleene()).unwrap()
     }
 
     fn repeat_range<R: RangeBounds<usize>>(self, r: R) -> Self {
-        unimplemented!();
+        let start = match r.start_bound() {
+            Included(&a) => a,
+            Excluded(&a) => a + 1,
+            Unbounded => 0,
+        };
+        // ***
+        let end_opt = match r.end_bound() {
+            Included(&a) => Some(a),
+            Excluded(&a) => Some(a - 1),
+            Unbounded => None,
+        };
+        // ***
+        match end_opt {
+            None => {
+                self.at_least(start)
+            },
+            Some(end) => {
+                if end < start {
+                    panic!()
+                } else {
+                    self.clone().repeat(start).concatenate(self.at_most(end - start)).unwrap()
+                }
+            }
+        }
     }
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::gnfa::gnfa::AutGNFA;
+    use crate::nfa::nfa::AutNFA;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::WITNESS_TEST_LANGUAGES;
+    use crate::traits::build::AutBuildable;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::error::AutError;
+    use crate::traits::translate::AutTranslatable;
+
+    /// checks that the GNFA has the language of *bre* and that its start (resp. accept) state has no incoming (resp. outgoing) edges
+    fn check_gnfa(gnfa : &AutGNFA<char>, bre : &ExpBRE<char>, nfa : &AutNFA<char>) {
+        assert_ne!(gnfa.start_state, gnfa.accept_state);
+        assert!(gnfa.get_incoming_transitions(gnfa.start_state).next().is_none());
+        assert!(gnfa.get_outgoing_transitions(gnfa.accept_state).next().is_none());
+        assert!(gnfa.to_dfa().equals(&bre.to_dfa()));
+        assert!(gnfa.to_dfa().equals(&nfa.to_dfa()));
+    }
+
+    #[test]
+    fn gnfa_build_test() {
+        let alphabet = hashset!{'a','b'};
+        let bres : Vec<ExpBRE<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap())
+            .collect();
+        for bre1 in &bres {
+            // going through the NFA yields GNFAs with intermediate states
+            let gnfa1 = bre1.to_nfa().to_gnfa();
+            check_gnfa(&gnfa1.clone().kleene(), &bre1.clone().kleene(), &bre1.to_nfa().kleene());
+            for num in 0..3 {
+                check_gnfa(&gnfa1.clone().repeat(num), &bre1.clone().repeat(num), &bre1.to_nfa().repeat(num));
+                check_gnfa(&gnfa1.clone().at_most(num), &bre1.clone().at_most(num), &bre1.to_nfa().at_most(num));
+                check_gnfa(&gnfa1.clone().at_least(num), &bre1.clone().at_least(num), &bre1.to_nfa().at_least(num));
+                check_gnfa(&gnfa1.clone().repeat_range(num..=2), &bre1.clone().repeat_range(num..=2), &bre1.to_nfa().repeat_range(num..=2));
+                check_gnfa(&gnfa1.clone().repeat_range(num..), &bre1.clone().repeat_range(num..), &bre1.to_nfa().repeat_range(num..));
+            }
+            for bre2 in &bres {
+                let gnfa2 = bre2.to_nfa().to_gnfa();
+                check_gnfa(&gnfa1.clone().unite(gnfa2.clone()).unwrap(),
+                           &bre1.clone().unite(bre2.clone()).unwrap(),
+                           &bre1.to_nfa().unite(bre2.to_nfa()).unwrap());
+                check_gnfa(&gnfa1.clone().concatenate(gnfa2).unwrap(),
+                           &bre1.clone().concatenate(bre2.clone()).unwrap(),
+                           &bre1.to_nfa().concatenate(bre2.to_nfa()).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn gnfa_build_alphabet_mismatch_test() {
+        let gnfa1 = parse_bre("ab",&hashset!{'a','b'}).unwrap().to_gnfa();
+        let gnfa2 = parse_bre("ac",&hashset!{'a','c'}).unwrap().to_gnfa();
+        assert!(matches!(gnfa1.clone().unite(gnfa2.clone()),
+                         Err(AutError::OperationOnLanguagesOverDifferentAlphabets(_,_))));
+        assert!(matches!(gnfa1.concatenate(gnfa2),
+                         Err(AutError::OperationOnLanguagesOverDifferentAlphabets(_,_))));
+    }
+}
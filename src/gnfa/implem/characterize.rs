@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 use crate::gnfa::gnfa::AutGNFA;
+use crate::traits::access::AutAccessible;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::letter::AutLetter;
 use crate::traits::translate::AutTranslatable;
@@ -26,8 +27,9 @@ impl<Letter: AutLetter> AutCharacterizable<Letter> for AutGNFA<Letter> {
         self.to_nfa().is_complete()
     }
 
+    // the accept state is accessible if and only if there is a path of edges with non-empty terms leading to it
     fn is_empty(&self) -> bool {
-        self.to_nfa().is_empty()
+        !self.get_all_accessible_states().contains(&self.accept_state)
     }
 
     fn is_universal(&self) -> bool {
@@ -38,4 +40,49 @@ impl<Letter: AutLetter> AutCharacterizable<Letter> for AutGNFA<Letter> {
                 other: &Self) -> bool {
         self.to_nfa().contains(&other.to_nfa())
     }
+
+    fn find_accepted_word(&self) -> Option<Vec<Letter>> {
+        self.to_nfait().find_accepted_word()
+    }
+
+    fn find_rejected_word(&self) -> Option<Vec<Letter>> {
+        self.to_nfait().find_rejected_word()
+    }
+
+    fn find_inclusion_counterexample(&self,
+                                     other: &Self) -> Option<Vec<Letter>> {
+        self.to_nfait().find_inclusion_counterexample(&other.to_nfait())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::gnfa::gnfa::AutGNFA;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::{check_witness_words, WITNESS_TEST_LANGUAGES};
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn gnfa_witness_words_test() {
+        let alphabet = hashset!{'a','b'};
+        let languages : Vec<AutGNFA<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap().to_gnfa())
+            .collect();
+        for aut1 in &languages {
+            for aut2 in &languages {
+                check_witness_words(aut1,aut2,&|aut,word| aut.runs_trace(word).unwrap(),4);
+            }
+        }
+        // no witness for the empty language, the universal language and included languages
+        assert_eq!(languages[0].find_accepted_word(), None);
+        assert_eq!(languages[1].find_rejected_word(), None);
+        assert_eq!(languages[4].find_inclusion_counterexample(&languages[3]), None);
+        assert_eq!(languages[1].find_inclusion_counterexample(&languages[6]), None);
+        assert_eq!(languages[3].find_inclusion_counterexample(&languages[4]).map(|word| word.len()), Some(3));
+        assert_eq!(languages[6].find_rejected_word(), Some(vec!['b','a']));
+    }
+
 }
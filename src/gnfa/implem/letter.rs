@@ -29,10 +29,10 @@ impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for AutGNFA<Letter> {
                           remove_from_alphabet : bool,
                           substitution : &dyn Fn(&Letter) -> Letter) -> Self {
         let mut new_transitions : HashMap<(usize,usize), TermBRE<Letter>> = hashmap!{};
-        for ((orig,targ),term) in self.transitions {
+        for ((orig,targ),term) in self.get_transitions() {
             new_transitions.insert(
                 (orig,targ),
-                term.substitute_letters(remove_from_alphabet,substitution)
+                term.clone().substitute_letters(remove_from_alphabet,substitution)
             );
         }
         AutGNFA::from_raw(
@@ -47,10 +47,10 @@ impl<Letter: AutLetter> AutAlphabetSubstitutable<Letter> for AutGNFA<Letter> {
                     remove_from_alphabet : bool,
                     should_hide : &dyn Fn(&Letter) -> bool) -> Self {
         let mut new_transitions : HashMap<(usize,usize), TermBRE<Letter>> = hashmap!{};
-        for ((orig,targ),term) in self.transitions {
+        for ((orig,targ),term) in self.get_transitions() {
             new_transitions.insert(
                 (orig,targ),
-                term.hide_letters(remove_from_alphabet,should_hide)
+                term.clone().hide_letters(remove_from_alphabet,should_hide)
             );
         }
         AutGNFA::from_raw(
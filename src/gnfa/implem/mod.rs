@@ -20,4 +20,7 @@ mod translate;
 mod to_dot;
 mod characterize;
 mod run;
-mod letter;
\ No newline at end of file
+mod letter;
+mod transform;
+#[cfg(feature = "serde")]
+mod serialize;
@@ -14,23 +14,69 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 use crate::traits::letter::AutLetter;
 use crate::traits::run::AutRunnable;
+use crate::gnfa::algos::execution::{get_initial_configurations, run_configurations, GnfaConfiguration};
 use crate::gnfa::gnfa::AutGNFA;
 use crate::traits::error::AutError;
-use crate::traits::translate::AutTranslatable;
 
 
+impl<Letter: AutLetter> AutGNFA<Letter> {
+
+    /// Returns the configurations in which a run can be before reading any letter (see "GnfaConfiguration")
+    pub fn get_initial_configurations(&self) -> BTreeSet<GnfaConfiguration<Letter>> {
+        get_initial_configurations(self)
+    }
+
+    /// Reads *letter* from *configurations* on the GNFA itself, by deriving the terms labelling the edges
+    pub fn run_configurations(&self,
+                              configurations : &BTreeSet<GnfaConfiguration<Letter>>,
+                              letter : &Letter) -> Result<BTreeSet<GnfaConfiguration<Letter>>,AutError<Letter>> {
+        run_configurations(self,configurations,letter)
+    }
+
+}
+
 impl<Letter: AutLetter> AutRunnable<Letter> for AutGNFA<Letter> {
     fn runs_trace(&self, trace : &[Letter]) -> Result<bool,AutError<Letter>> {
-        self.to_dfa().runs_trace(trace)
+        let mut configurations = self.get_initial_configurations();
+        for letter in trace {
+            configurations = self.run_configurations(&configurations,letter)?;
+            if configurations.is_empty() {
+                return Ok(false);
+            }
+        }
+        Ok(configurations.contains(&GnfaConfiguration::AtState(self.accept_state)))
     }
 
+    /// Returns the states which can be reached from *active_states* by reading only *letter*.
+    /// Runs which are still within an edge after reading *letter* cannot be represented by states
+    /// so an error is returned if some of them may read further letters on that edge (see "run_configurations").
     fn run_transition(&self,
                       active_states: &HashSet<usize>,
                       letter: &Letter) ->  Result<HashSet<usize>,AutError<Letter>> {
-        unimplemented!()
+        let configurations = active_states.iter()
+            .map(|state| GnfaConfiguration::AtState(*state))
+            .collect();
+        let reached = self.run_configurations(&configurations,letter)?;
+        for configuration in &reached {
+            if let GnfaConfiguration::WithinEdge(orig,targ,residual) = configuration {
+                // a run which has just entered an edge is represented by the origin of the edge
+                // and a residual which only expresses 𝜀 has already led to the target of the edge
+                let just_entered = reached.contains(&GnfaConfiguration::AtState(*orig))
+                    && self.get_transition(*orig,*targ) == Some(residual);
+                if !just_entered && self.alphabet.iter().any(|next| !residual.derivative(next).is_empty()) {
+                    return Err(AutError::Other(format!("reading {:?} leads within the edge from {} to {} which cannot be represented by a set of states", letter, orig, targ)));
+                }
+            }
+        }
+        Ok(reached.into_iter()
+            .filter_map(|configuration| match configuration {
+                GnfaConfiguration::AtState(state) => Some(state),
+                _ => None
+            })
+            .collect())
     }
-}
\ No newline at end of file
+}
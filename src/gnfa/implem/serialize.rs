@@ -0,0 +1,100 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use maplit::hashmap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::bre::term::TermBRE;
+use crate::gnfa::gnfa::AutGNFA;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "states_num":n, "start_state":state, "accept_state":state, "transitions":[[orig,targ,term],..]}
+/// where "transitions" lists the non-empty edges sorted by origin and then by target
+#[derive(Serialize, Deserialize)]
+struct GNFALayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    states_num : usize,
+    start_state : usize,
+    accept_state : usize,
+    transitions : Vec<(usize,usize,TermBRE<Letter>)>
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for AutGNFA<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GNFALayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            states_num : self.states_num,
+            start_state : self.start_state,
+            accept_state : self.accept_state,
+            transitions : self.get_transitions().map(|((orig,targ),term)| (orig,targ,term.clone())).collect()
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for AutGNFA<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = GNFALayout::<Letter>::deserialize(deserializer)?;
+        let mut gnfa = AutGNFA::from_raw(layout.alphabet.into_iter().collect(),
+                                         layout.states_num,
+                                         layout.start_state,
+                                         layout.accept_state,
+                                         hashmap!{}).map_err(D::Error::custom)?;
+        // edges are set one by one so that an edge given several times is rejected instead of being overwritten
+        for (orig,targ,term) in layout.transitions {
+            if gnfa.get_transition(orig,targ).is_some() {
+                return Err(D::Error::custom(AutError::<Letter>::Other(format!("several edges from {:} to {:}", orig, targ))));
+            }
+            gnfa.set_transition(orig,targ,term).map_err(D::Error::custom)?;
+        }
+        Ok(gnfa)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::gnfa::gnfa::AutGNFA;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn gnfa_serialization_test() {
+        let gnfa = parse_bre("(a|b)*.a.b",&hashset!{'a','b'}).unwrap().to_nfa().to_gnfa();
+        let json = serde_json::to_string(&gnfa).unwrap();
+        let got : AutGNFA<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&got).unwrap(), json);
+        assert!(got.runs_trace(&['b','a','b']).unwrap());
+        assert!(!got.runs_trace(&['b','a']).unwrap());
+        // terms are serialized as externally tagged enums
+        let single = r#"{"alphabet":["a"],"states_num":2,"start_state":0,"accept_state":1,"transitions":[[0,1,{"Kleene":{"Literal":"a"}}]]}"#;
+        let got : AutGNFA<char> = serde_json::from_str(single).unwrap();
+        assert_eq!(serde_json::to_string(&got).unwrap(), single);
+        // an edge entering the start state
+        let invalid = r#"{"alphabet":["a"],"states_num":2,"start_state":0,"accept_state":1,"transitions":[[1,0,"Epsilon"]]}"#;
+        assert!(serde_json::from_str::<AutGNFA<char>>(invalid).is_err());
+        // an edge given twice
+        let invalid = r#"{"alphabet":["a"],"states_num":2,"start_state":0,"accept_state":1,"transitions":[[0,1,"Epsilon"],[0,1,"Epsilon"]]}"#;
+        assert!(serde_json::from_str::<AutGNFA<char>>(invalid).is_err());
+    }
+}
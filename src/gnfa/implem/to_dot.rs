@@ -14,7 +14,7 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use graphviz_dot_builder::edge::edge::GraphVizEdge;
 use graphviz_dot_builder::edge::style::{GraphvizEdgeStyleItem};
@@ -23,6 +23,8 @@ use graphviz_dot_builder::item::node::node::GraphVizNode;
 use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape};
 use graphviz_dot_builder::traits::DotBuildable;
 
+use crate::bre::term::TermBRE;
+use crate::gnfa::algos::execution::GnfaConfiguration;
 use crate::gnfa::gnfa::AutGNFA;
 use crate::traits::access::AutAccessible;
 use crate::traits::letter::AutLetter;
@@ -30,14 +32,34 @@ use crate::traits::repr::{AbstractLanguagePrinter, AUT_COLOR_ACCESSIBLE_STATE, A
 
 
 
-impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for AutGNFA<Letter> where
-    Letter : AutLetter,
-    Printer : AbstractLanguagePrinter<Letter> {
+impl<Letter : AutLetter> AutGNFA<Letter> {
 
-    fn to_dot(&self,
-              draw_accessibility : bool,
-              active_states : &HashSet<usize>,
-              printer : &Printer) -> GraphVizDiGraph {
+    /// Draws the GNFA with the states and edges involved in *configurations* being highlighted (see "GnfaConfiguration").
+    /// The residuals of the active edges are given after their labels.
+    pub fn to_dot_with_configurations<Printer : AbstractLanguagePrinter<Letter>>(&self,
+                                                                               draw_accessibility : bool,
+                                                                               configurations : &BTreeSet<GnfaConfiguration<Letter>>,
+                                                                               printer : &Printer) -> GraphVizDiGraph {
+        let mut active_states = HashSet::new();
+        let mut active_edges : HashMap<(usize,usize),Vec<&TermBRE<Letter>>> = HashMap::new();
+        for configuration in configurations {
+            match configuration {
+                GnfaConfiguration::AtState(state) => {
+                    active_states.insert(*state);
+                },
+                GnfaConfiguration::WithinEdge(orig,targ,residual) => {
+                    active_edges.entry((*orig,*targ)).or_default().push(residual);
+                }
+            }
+        }
+        self.draw(draw_accessibility,&active_states,&active_edges,printer)
+    }
+
+    fn draw<Printer : AbstractLanguagePrinter<Letter>>(&self,
+                                                       draw_accessibility : bool,
+                                                       active_states : &HashSet<usize>,
+                                                       active_edges : &HashMap<(usize,usize),Vec<&TermBRE<Letter>>>,
+                                                       printer : &Printer) -> GraphVizDiGraph {
         let accessible_states = self.get_all_accessible_states();
         let coaccessible_states = self.get_all_coaccessible_states();
         // ***
@@ -83,21 +105,25 @@ impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for AutGNFA<Letter> w
             }
         }
         // ***
-        for ((orig_stid,targ_stid), term) in &self.transitions {
-            if !term.is_empty() {
-                let orig_name = format!("S{}",orig_stid);
-                let targ_name = format!("S{}",targ_stid);
-                let term_as_str = term.regexp_to_string( true, printer);
-                let edge_style = vec![
-                    GraphvizEdgeStyleItem::Label(term_as_str)
-                ];
-                let edge = GraphVizEdge::new(orig_name.clone(),
-                                             None,
-                                             targ_name,
-                                             None,
-                                             edge_style);
-                digraph.add_edge(edge);
+        for ((orig_stid,targ_stid), term) in self.get_transitions() {
+            let orig_name = format!("S{}",orig_stid);
+            let targ_name = format!("S{}",targ_stid);
+            let mut term_as_str = term.regexp_to_string( true, printer);
+            let mut edge_style = vec![];
+            if let Some(residuals) = active_edges.get(&(orig_stid,targ_stid)) {
+                let residuals_as_str : Vec<String> = residuals.iter()
+                    .map(|residual| residual.regexp_to_string(true, printer))
+                    .collect();
+                term_as_str = format!("{} [{}]", term_as_str, residuals_as_str.join(" ; "));
+                edge_style.push(GraphvizEdgeStyleItem::Color(AUT_COLOR_ACTIVE_STATE));
             }
+            edge_style.push(GraphvizEdgeStyleItem::Label(term_as_str));
+            let edge = GraphVizEdge::new(orig_name.clone(),
+                                         None,
+                                         targ_name,
+                                         None,
+                                         edge_style);
+            digraph.add_edge(edge);
         }
         // ***
         digraph
@@ -105,4 +131,15 @@ impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for AutGNFA<Letter> w
 
 }
 
+impl<Letter, Printer> AutGraphvizDrawable<Letter, Printer> for AutGNFA<Letter> where
+    Letter : AutLetter,
+    Printer : AbstractLanguagePrinter<Letter> {
+
+    fn to_dot(&self,
+              draw_accessibility : bool,
+              active_states : &HashSet<usize>,
+              printer : &Printer) -> GraphVizDiGraph {
+        self.draw(draw_accessibility,active_states,&HashMap::new(),printer)
+    }
 
+}
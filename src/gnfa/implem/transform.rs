@@ -0,0 +1,108 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::gnfa::gnfa::AutGNFA;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+use crate::traits::transform::AutTransformable;
+use crate::traits::translate::AutTranslatable;
+
+
+impl<Letter: AutLetter> AutTransformable<Letter> for AutGNFA<Letter> {
+
+    fn complete(self) -> Self {
+        self.to_nfait().complete().to_gnfa()
+    }
+
+    fn negate(self) -> Self {
+        self.to_dfa().negate().to_gnfa()
+    }
+
+    // the start and accept states are swapped and each edge is reversed along with its term
+    fn reverse(self) -> Self {
+        let transitions = self.get_transitions()
+            .map(|((orig,targ),term)| ((targ,orig),term.clone().reverse()))
+            .collect();
+        Self::from_raw(self.alphabet,
+                       self.states_num,
+                       self.accept_state,
+                       self.start_state,
+                       transitions).unwrap()
+    }
+
+    fn minimize(self) -> Self {
+        self.to_dfa().minimize().to_gnfa()
+    }
+
+    fn intersect(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfait().intersect(other.to_nfait()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_gnfa())}
+        }
+    }
+
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfait().difference(other.to_nfait()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_gnfa())}
+        }
+    }
+
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_dfa().symmetric_difference(other.to_dfa()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_gnfa())}
+        }
+    }
+
+    fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_nfait().interleave(other.to_nfait()) {
+            Err(e) => {Err(e)},
+            Ok(got) => {Ok(got.to_gnfa())}
+        }
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::random::params::RandomAutomatonParameters;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::transform::AutTransformable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::for_each_random_nfait;
+
+    #[test]
+    fn gnfa_reverse_test() {
+        let mut params = RandomAutomatonParameters::new(hashset!{'a','b'},5);
+        params.transition_density = 0.3;
+        params.epsilon_density = 0.1;
+        for_each_random_nfait(11,20,&params,|nfait| {
+            let gnfa = nfait.to_gnfa();
+            let reversed = gnfa.clone().reverse();
+            assert_eq!(reversed.start_state, gnfa.accept_state);
+            assert_eq!(reversed.accept_state, gnfa.start_state);
+            assert!(reversed.get_incoming_transitions(reversed.start_state).next().is_none());
+            assert!(reversed.get_outgoing_transitions(reversed.accept_state).next().is_none());
+            assert!(reversed.to_dfa().equals(&gnfa.to_dfa().reverse()));
+        });
+    }
+}
@@ -14,19 +14,32 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::{HashMap, HashSet};
-use maplit::{hashset,hashmap};
+use maplit::hashset;
 
+use crate::bre::algos::thompson::ThompsonBuilder;
 use crate::bre::bre::ExpBRE;
 use crate::dfa::dfa::AutDFA;
 use crate::traits::letter::AutLetter;
 use crate::traits::translate::AutTranslatable;
+use crate::gnfa::algos::elimination::{eliminate_states, StateEliminationOptions};
 use crate::gnfa::gnfa::AutGNFA;
 use crate::nfa::nfa::AutNFA;
 use crate::nfait::nfait::AutNFAIT;
-use crate::traits::access::AutAccessible;
+use crate::traits::error::AutError;
 
 
+impl<Letter : AutLetter> AutGNFA<Letter> {
+
+    /// Translates the GNFA into a regular expression via state elimination (see "eliminate_states")
+    /// and simplifies the resulting term
+    pub fn to_bre_with_options(&self,
+                               options : StateEliminationOptions<Letter>) -> Result<ExpBRE<Letter>,AutError<Letter>> {
+        let term = eliminate_states(self,options)?.simplify();
+        ExpBRE::from_raw(self.alphabet.clone(),term)
+    }
+
+}
+
 impl<Letter : AutLetter> AutTranslatable<Letter> for AutGNFA<Letter> {
     fn to_dfa(&self) -> AutDFA<Letter> {
         self.to_nfait().to_dfa()
@@ -36,76 +49,21 @@ impl<Letter : AutLetter> AutTranslatable<Letter> for AutGNFA<Letter> {
         self.to_nfait().to_nfa()
     }
 
+    // Thompson
     fn to_nfait(&self) -> AutNFAIT<Letter> {
-        let new_nfa_initials = hashset!{0};
-        let new_nfa_finals = hashset!{1};
-        let mut current_number_of_states = 2;
-        let mut gnfa_to_nfait_states_map : HashMap<usize,usize> = hashmap!{self.start_state => 0, self.accept_state => 1};
-        // ***
-        let mut transitions_to_add = vec![];
-        for ((orig_stid,targ_stid), term) in &self.transitions {
-            if !term.is_empty() {
-                transitions_to_add.push((orig_stid,targ_stid,term));
-            }
-        }
-        // ***
-        let mut new_nfa_transitions = vec![hashmap!{},hashmap!{}];
-        let mut new_nfa_epstrans = vec![hashset!{},hashset!{}];
-        // ***
-        while !transitions_to_add.is_empty() {
-            let mut pop_index : Option<usize> = None;
-            for (idx,(orig,_,_)) in transitions_to_add.iter().enumerate() {
-                if gnfa_to_nfait_states_map.contains_key(orig) {
-                    pop_index = Some(idx);
-                    break;
-                }
-            }
-            match pop_index {
-                None => {
-                    break;
-                },
-                Some(idx) => {
-                    let (orig_in_gnfa,targ_in_gnfa,term) = transitions_to_add.remove(idx);
-                    //println!("{:?}", term);
-                    let regexp = ExpBRE::from_raw(self.alphabet.clone(),term.clone()).unwrap();
-                    // at first to DFA so as to simplify the NFAIT
-                    let mut regexp_as_nfait = regexp.to_dfa().to_nfait();
-                    regexp_as_nfait.shift_nfait(current_number_of_states);
-                    //println!("{:?}", regexp_as_nfait);
-                    current_number_of_states += regexp_as_nfait.transitions.len();
-                    new_nfa_transitions.append(&mut regexp_as_nfait.transitions);
-                    new_nfa_epstrans.append(&mut regexp_as_nfait.epsilon_trans);
-                    // ***
-                    let orig_in_nfait = gnfa_to_nfait_states_map.get(orig_in_gnfa).unwrap();
-                    let targets_of_orig : &mut HashSet<usize> = new_nfa_epstrans.get_mut(*orig_in_nfait).unwrap();
-                    targets_of_orig.extend(regexp_as_nfait.initials);
-                    // ***
-                    let targ_in_nfait = match gnfa_to_nfait_states_map.get(targ_in_gnfa) {
-                        None => {
-                            gnfa_to_nfait_states_map.insert(*targ_in_gnfa,current_number_of_states);
-                            let targ = current_number_of_states;
-                            current_number_of_states += 1;
-                            new_nfa_transitions.push(hashmap!{});
-                            new_nfa_epstrans.push(hashset!{});
-                            targ
-                        },
-                        Some(targ) => {
-                            *targ
-                        }
-                    };
-                    for regexp_final_stid in regexp_as_nfait.finals {
-                        let targets : &mut HashSet<usize> = new_nfa_epstrans.get_mut(regexp_final_stid).unwrap();
-                        targets.insert(targ_in_nfait);
-                    }
-                    // ***
-                }
-            }
+        // the first states of the NFAIT are those of the GNFA
+        let mut builder = ThompsonBuilder::new(self.states_num);
+        // edges are expanded in a fixed order so that the numbering of the states is deterministic
+        for ((orig,targ),term) in self.get_transitions() {
+            let (entry,exit) = builder.add_term(term);
+            builder.add_epsilon(orig,entry);
+            builder.add_epsilon(exit,targ);
         }
         AutNFAIT::from_raw(self.alphabet.clone(),
-                           new_nfa_initials,
-                           new_nfa_finals,
-                           new_nfa_transitions,
-                           new_nfa_epstrans).unwrap()
+                           hashset!{self.start_state},
+                           hashset!{self.accept_state},
+                           builder.transitions,
+                           builder.epsilon_trans).unwrap()
     }
 
     fn to_gnfa(&self) -> AutGNFA<Letter> {
@@ -113,22 +71,6 @@ impl<Letter : AutLetter> AutTranslatable<Letter> for AutGNFA<Letter> {
     }
 
     fn to_bre(&self) -> ExpBRE<Letter> {
-        println!("initial : {:?}", self);
-        let mut new_gnfa = self.clone().trim();
-        println!("trimmed then got : {:?}", new_gnfa);
-        while new_gnfa.states_num > 2 {
-            let mut rem_states : HashSet<usize> = (0..new_gnfa.states_num).collect();
-            rem_states.remove(&new_gnfa.start_state);
-            rem_states.remove(&new_gnfa.accept_state);
-            let to_rip_id = rem_states.iter().next().unwrap();
-            new_gnfa = new_gnfa.rip_state(*to_rip_id).unwrap();
-            println!("removing state {:?} then got : {:?}", to_rip_id, new_gnfa);
-            new_gnfa = new_gnfa.trim();
-            println!("trimmed then got : {:?}", new_gnfa);
-        }
-        // ***
-        let bre = new_gnfa.transitions.get(&(0,1)).unwrap();
-        // ***
-        ExpBRE::from_raw(self.alphabet.clone(),bre.clone()).unwrap()
+        self.to_bre_with_options(StateEliminationOptions::default()).unwrap()
     }
-}
\ No newline at end of file
+}
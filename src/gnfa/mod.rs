@@ -17,4 +17,5 @@ limitations under the License.
 
 pub mod gnfa;
 mod implem;
+pub mod algos;
 
@@ -0,0 +1,111 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt::Write;
+
+use crate::jflap::jff::JflapAutomaton;
+
+
+/// Writes the automaton in the format of JFLAP ".jff" files, the id of each state being its number.
+/// Immediate transitions are written with an empty label.
+/// As JFLAP doesn't store alphabets, letters which do not label any transition are lost.
+pub fn write_jff(automaton : &JflapAutomaton) -> String {
+    let nfait = automaton.get_nfait();
+    let mut jff = String::new();
+    jff.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n");
+    jff.push_str("<structure>\n\t<type>fa</type>\n\t<automaton>\n");
+    for (id,state) in automaton.get_states().iter().enumerate() {
+        writeln!(jff, "\t\t<state id=\"{}\" name=\"{}\">", id, escape_xml(&state.name)).unwrap();
+        writeln!(jff, "\t\t\t<x>{:?}</x>\n\t\t\t<y>{:?}</y>", state.x, state.y).unwrap();
+        if nfait.initials.contains(&id) {
+            jff.push_str("\t\t\t<initial/>\n");
+        }
+        if nfait.finals.contains(&id) {
+            jff.push_str("\t\t\t<final/>\n");
+        }
+        jff.push_str("\t\t</state>\n");
+    }
+    // transitions are sorted so that the output is deterministic
+    for orig in 0..automaton.get_states().len() {
+        let mut letters : Vec<&char> = nfait.transitions[orig].keys().collect();
+        letters.sort();
+        for letter in letters {
+            let mut targets : Vec<&usize> = nfait.transitions[orig].get(letter).unwrap().iter().collect();
+            targets.sort();
+            for targ in targets {
+                write_transition(&mut jff, orig, *targ, Some(*letter));
+            }
+        }
+        // the immediate transitions of the last states may be omitted
+        let mut epsilon_targets : Vec<&usize> = nfait.epsilon_trans.get(orig).into_iter().flatten().collect();
+        epsilon_targets.sort();
+        for targ in epsilon_targets {
+            write_transition(&mut jff, orig, *targ, None);
+        }
+    }
+    jff.push_str("\t</automaton>\n</structure>\n");
+    jff
+}
+
+fn write_transition(jff : &mut String, orig : usize, targ : usize, letter : Option<char>) {
+    writeln!(jff, "\t\t<transition>\n\t\t\t<from>{}</from>\n\t\t\t<to>{}</to>", orig, targ).unwrap();
+    match letter {
+        None => {
+            jff.push_str("\t\t\t<read/>\n");
+        },
+        Some(letter) => {
+            writeln!(jff, "\t\t\t<read>{}</read>", escape_xml(&letter.to_string())).unwrap();
+        }
+    }
+    jff.push_str("\t\t</transition>\n");
+}
+
+fn escape_xml(text : &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+    use crate::jflap::export::write_jff;
+    use crate::jflap::import::parse_jff;
+    use crate::jflap::jff::{JflapAutomaton, JflapState};
+    use crate::nfait::nfait::AutNFAIT;
+
+    #[test]
+    fn jff_export_short_epsilon_transitions_test() {
+        // the second state has no entry in "epsilon_trans"
+        let nfait = AutNFAIT::from_raw(hashset!{'a'},
+                                       hashset!{0},
+                                       hashset!{1},
+                                       vec![hashmap!{'a' => hashset!{1}}, hashmap!{}],
+                                       vec![hashset!{1}]).unwrap();
+        let automaton = JflapAutomaton::from_nfait(nfait);
+        let again = parse_jff(&write_jff(&automaton)).unwrap();
+        assert_eq!(again.get_nfait().transitions, automaton.get_nfait().transitions);
+        assert_eq!(again.get_nfait().epsilon_trans, vec![hashset!{1}, hashset!{}]);
+        // states must be described one by one
+        let state = JflapState{name:"q0".to_string(),x:0.0,y:0.0};
+        assert!(JflapAutomaton::new(automaton.get_nfait().clone(),vec![state]).is_err());
+    }
+}
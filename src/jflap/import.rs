@@ -0,0 +1,199 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashMap, HashSet};
+use roxmltree::Node;
+
+use crate::jflap::jff::{JflapAutomaton, JflapState};
+use crate::nfait::nfait::AutNFAIT;
+use crate::traits::error::AutError;
+
+
+/// Reads a finite automaton from the content of a JFLAP ".jff" file.
+/// States are numbered in the order in which they appear in the file
+/// and the alphabet is made of the letters which label transitions.
+/// Transitions with an empty label become immediate transitions while labels of several characters are not supported.
+pub fn parse_jff(text : &str) -> Result<JflapAutomaton,AutError<char>> {
+    let document = roxmltree::Document::parse(text)
+        .map_err(|e| malformed(format!("{}", e)))?;
+    let structure = document.root_element();
+    if !structure.has_tag_name("structure") {
+        return Err(malformed("the root element is not 'structure'".to_string()));
+    }
+    match get_child(structure,"type").and_then(|node| node.text()) {
+        Some("fa") => {},
+        got => {
+            return Err(malformed(format!("expected an automaton of type 'fa', got {:?}", got)));
+        }
+    }
+    // JFLAP 7 puts states and transitions in an 'automaton' element while former versions put them in the structure
+    let automaton = get_child(structure,"automaton").unwrap_or(structure);
+    // ***
+    let mut states = vec![];
+    let mut states_ids : HashMap<&str,usize> = HashMap::new();
+    let mut initials = HashSet::new();
+    let mut finals = HashSet::new();
+    for node in automaton.children().filter(|node| node.has_tag_name("state")) {
+        let id = node.attribute("id").ok_or_else(|| malformed("a state has no id".to_string()))?;
+        let state = states.len();
+        if states_ids.insert(id,state).is_some() {
+            return Err(malformed(format!("several states have the id '{}'", id)));
+        }
+        if get_child(node,"initial").is_some() {
+            initials.insert(state);
+        }
+        if get_child(node,"final").is_some() {
+            finals.insert(state);
+        }
+        let name = match node.attribute("name") {
+            None => format!("q{}", id),
+            Some(name) => name.to_string()
+        };
+        states.push(JflapState{name,x:get_coordinate(node,"x")?,y:get_coordinate(node,"y")?});
+    }
+    // ***
+    let mut alphabet = HashSet::new();
+    let mut transitions : Vec<HashMap<char,HashSet<usize>>> = vec![HashMap::new();states.len()];
+    let mut epsilon_trans : Vec<HashSet<usize>> = vec![HashSet::new();states.len()];
+    for node in automaton.children().filter(|node| node.has_tag_name("transition")) {
+        let orig = get_state(node,"from",&states_ids)?;
+        let targ = get_state(node,"to",&states_ids)?;
+        let label = get_child(node,"read").and_then(|read| read.text()).unwrap_or("");
+        let mut chars = label.chars();
+        match (chars.next(),chars.next()) {
+            (None,_) => {
+                epsilon_trans[orig].insert(targ);
+            },
+            (Some(letter),None) => {
+                alphabet.insert(letter);
+                transitions[orig].entry(letter).or_default().insert(targ);
+            },
+            _ => {
+                return Err(malformed(format!("the label '{}' has several characters", label)));
+            }
+        }
+    }
+    // ***
+    let nfait = AutNFAIT::from_raw(alphabet,initials,finals,transitions,epsilon_trans)?;
+    JflapAutomaton::new(nfait,states)
+}
+
+fn malformed(msg : String) -> AutError<char> {
+    AutError::Other(format!("malformed JFLAP file : {}", msg))
+}
+
+fn get_child<'a,'input>(node : Node<'a,'input>, tag : &str) -> Option<Node<'a,'input>> {
+    node.children().find(|child| child.has_tag_name(tag))
+}
+
+/// Coordinates are optional and default to 0
+fn get_coordinate(state : Node, tag : &str) -> Result<f64,AutError<char>> {
+    match get_child(state,tag).and_then(|node| node.text()) {
+        None => Ok(0.0),
+        Some(text) => text.trim().parse::<f64>()
+            .map_err(|_| malformed(format!("invalid coordinate '{}'", text)))
+    }
+}
+
+fn get_state(transition : Node,
+             tag : &str,
+             states_ids : &HashMap<&str,usize>) -> Result<usize,AutError<char>> {
+    let id = get_child(transition,tag).and_then(|node| node.text())
+        .ok_or_else(|| malformed(format!("a transition has no '{}'", tag)))?;
+    states_ids.get(id.trim()).cloned()
+        .ok_or_else(|| malformed(format!("a transition refers to the unknown state id '{}'", id)))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::jflap::export::write_jff;
+    use crate::jflap::import::parse_jff;
+    use crate::jflap::jff::JflapState;
+    use crate::traits::run::AutRunnable;
+
+    /// accepts a.b* or 𝜀, as saved by JFLAP 7
+    const EXAMPLE : &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?><!--Created with JFLAP 7.1.--><structure>
+	<type>fa</type>
+	<automaton>
+		<!--The list of states.-->
+		<state id="3" name="start">
+			<x>66.0</x>
+			<y>136.5</y>
+			<initial/>
+		</state>
+		<state id="7" name="loop">
+			<x>183.0</x>
+			<y>135.0</y>
+			<final/>
+		</state>
+		<!--The list of transitions.-->
+		<transition>
+			<from>3</from>
+			<to>7</to>
+			<read>a</read>
+		</transition>
+		<transition>
+			<from>7</from>
+			<to>7</to>
+			<read>b</read>
+		</transition>
+		<transition>
+			<from>3</from>
+			<to>7</to>
+			<read/>
+		</transition>
+	</automaton>
+</structure>"#;
+
+    #[test]
+    fn jff_import_export_test() {
+        let automaton = parse_jff(EXAMPLE).unwrap();
+        assert_eq!(automaton.get_states()[0], JflapState{name:"start".to_string(),x:66.0,y:136.5});
+        assert_eq!(automaton.get_states()[1].name, "loop");
+        assert_eq!(automaton.get_nfait().epsilon_trans[0].len(), 1);
+        for (word,expected) in [(vec![],true),(vec!['a'],true),(vec!['b','b'],true),(vec!['a','a'],false),(vec!['b','a'],false)] {
+            assert_eq!(automaton.get_nfait().runs_trace(&word).unwrap(), expected);
+        }
+        // immediate transitions prevent translating into a NFA with the same states
+        assert!(automaton.to_nfa().is_err());
+        assert!(automaton.to_dfa().is_err());
+        // ***
+        let exported = write_jff(&automaton);
+        let again = parse_jff(&exported).unwrap();
+        assert_eq!(again.get_states(), automaton.get_states());
+        assert_eq!(again.get_nfait().transitions, automaton.get_nfait().transitions);
+        assert_eq!(again.get_nfait().epsilon_trans, automaton.get_nfait().epsilon_trans);
+        assert_eq!(write_jff(&again), exported);
+        // ***
+        let without_epsilon = EXAMPLE.replace("\t\t\t<read/>\n","\t\t\t<read>b</read>\n");
+        let dfa = parse_jff(&without_epsilon).unwrap().to_dfa().unwrap();
+        assert!(dfa.runs_trace(&['b','b']).unwrap());
+        assert!(!dfa.runs_trace(&[]).unwrap());
+    }
+
+    #[test]
+    fn malformed_jff_test() {
+        assert!(parse_jff("<structure><type>fa</type>").is_err());
+        assert!(parse_jff(&EXAMPLE.replace("<type>fa</type>","<type>pda</type>")).is_err());
+        assert!(parse_jff(&EXAMPLE.replace("<to>7</to>","<to>8</to>")).is_err());
+        assert!(parse_jff(&EXAMPLE.replace("<read>b</read>","<read>bb</read>")).is_err());
+        assert!(parse_jff(&EXAMPLE.replace("<x>66.0</x>","<x>left</x>")).is_err());
+        assert!(parse_jff(&EXAMPLE.replace("id=\"7\"","id=\"3\"")).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+use crate::dfa::dfa::AutDFA;
+use crate::nfa::nfa::AutNFA;
+use crate::nfait::nfait::AutNFAIT;
+use crate::traits::error::AutError;
+use crate::traits::translate::AutTranslatable;
+
+
+/// The name of a state and its position in the JFLAP editor
+#[derive(Debug, Clone, PartialEq)]
+pub struct JflapState {
+    pub name : String,
+    pub x : f64,
+    pub y : f64
+}
+
+/// A finite automaton as represented in a JFLAP ".jff" file.
+/// The *i*-th element of *states* describes the *i*-th state of *nfait*
+/// and transitions with an empty label in JFLAP are the immediate transitions of *nfait*.
+#[derive(Debug, Clone)]
+pub struct JflapAutomaton {
+    nfait : AutNFAIT<char>,
+    states : Vec<JflapState>
+}
+
+impl JflapAutomaton {
+
+    /// Returns an error if there are not as many elements in *states* as there are states in *nfait*
+    pub fn new(nfait : AutNFAIT<char>, states : Vec<JflapState>) -> Result<Self,AutError<char>> {
+        if states.len() != nfait.transitions.len() {
+            return Err(AutError::Other(format!("{} states are described while the automaton has {} states", states.len(), nfait.transitions.len())));
+        }
+        Ok(JflapAutomaton{nfait,states})
+    }
+
+    pub fn get_nfait(&self) -> &AutNFAIT<char> {
+        &self.nfait
+    }
+
+    pub fn get_states(&self) -> &[JflapState] {
+        &self.states
+    }
+
+    /// Names the states q0, q1, etc. and places them on a grid
+    pub fn from_nfait(nfait : AutNFAIT<char>) -> Self {
+        let states = (0..nfait.transitions.len())
+            .map(|state| JflapState{
+                name : format!("q{}", state),
+                x : 100.0 + 150.0 * ((state % 5) as f64),
+                y : 100.0 + 150.0 * ((state / 5) as f64)
+            })
+            .collect();
+        JflapAutomaton{nfait,states}
+    }
+
+    pub fn from_nfa(nfa : &AutNFA<char>) -> Self {
+        Self::from_nfait(nfa.to_nfait())
+    }
+
+    pub fn from_dfa(dfa : &AutDFA<char>) -> Self {
+        Self::from_nfait(dfa.to_nfait())
+    }
+
+    /// Returns an NFA with the same states, provided that there are no transitions with an empty label
+    pub fn to_nfa(&self) -> Result<AutNFA<char>,AutError<char>> {
+        if let Some(orig) = self.nfait.epsilon_trans.iter().position(|targets| !targets.is_empty()) {
+            return Err(AutError::Other(format!("state '{}' has an outgoing transition with an empty label", self.states[orig].name)));
+        }
+        AutNFA::from_raw(self.nfait.alphabet.clone(),
+                         self.nfait.initials.clone(),
+                         self.nfait.finals.clone(),
+                         self.nfait.transitions.clone())
+    }
+
+    /// Returns a DFA with the same states, provided that there is a single initial state
+    /// and that there are no transitions with an empty label and no two transitions from the same state with the same letter
+    pub fn to_dfa(&self) -> Result<AutDFA<char>,AutError<char>> {
+        let nfa = self.to_nfa()?;
+        if nfa.initials.len() != 1 {
+            return Err(AutError::Other(format!("a DFA must have exactly one initial state, got {}", nfa.initials.len())));
+        }
+        let mut transitions = vec![];
+        for (orig,map) in nfa.transitions.into_iter().enumerate() {
+            let mut dfa_map = HashMap::new();
+            for (letter,targets) in map {
+                if targets.len() > 1 {
+                    return Err(AutError::Other(format!("state '{}' has several transitions labelled by '{}'", self.states[orig].name, letter)));
+                }
+                if let Some(targ) = targets.into_iter().next() {
+                    dfa_map.insert(letter,targ);
+                }
+            }
+            transitions.push(dfa_map);
+        }
+        let initial = *nfa.initials.iter().next().unwrap();
+        AutDFA::from_raw(nfa.alphabet, initial, nfa.finals, transitions)
+    }
+
+}
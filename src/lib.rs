@@ -21,9 +21,15 @@ pub mod nfa;
 pub mod nfait;
 pub mod gnfa;
 pub mod bre;
-//pub mod ere;
+pub mod ere;
 mod utils;
 pub mod printers;
+pub mod parsers;
+#[cfg(feature = "jflap")]
+pub mod jflap;
+pub mod random;
+#[cfg(test)]
+mod tests_utils;
 
 
 
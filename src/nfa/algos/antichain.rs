@@ -0,0 +1,278 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use crate::nfa::nfa::AutNFA;
+use crate::traits::letter::AutLetter;
+
+
+/// A macro-state of the inclusion check of L(smaller) in L(larger):
+/// a state of smaller and the set of states of larger which are reached with the same word,
+/// along with the length of that word and the macro-state and letter from which it is reached.
+struct MacroState<Letter : AutLetter> {
+    state : usize,
+    states_set : BTreeSet<usize>,
+    depth : usize,
+    origin : Option<(usize,Letter)>
+}
+
+
+/// Returns None if each word accepted by *smaller* is also accepted by *larger*
+/// and otherwise a shortest word accepted by *smaller* but not by *larger*.
+///
+/// This is the antichain algorithm of De Wulf, Doyen, Henzinger and Raskin:
+/// the subset construction of *larger* is explored lazily, in breadth first order, alongside the states of *smaller*
+/// and macro-states which are subsumed by an already explored one are pruned.
+/// Macro-state (p,P) is subsumed by (r,R) if p=r and R⊆P.
+///
+/// If *use_simulation* is true, a forward simulation preorder ≼ is first computed on the states of both automata
+/// (following Abdulla, Chen, Holík, Mayr and Vojnar) and (p,P) is subsumed by (r,R) if p≼r and each state of R is simulated by a state of P.
+/// Macro-states (p,P) such that p is simulated by a state of P are also discarded as they cannot lead to a counterexample.
+pub fn antichain_inclusion_counterexample<Letter : AutLetter>(smaller : &AutNFA<Letter>,
+                                                              larger : &AutNFA<Letter>,
+                                                              use_simulation : bool) -> Option<Vec<Letter>> {
+    // letters outside the alphabet of smaller never occur in words accepted by smaller
+    let mut letters : Vec<Letter> = smaller.alphabet.iter().cloned().collect();
+    letters.sort();
+    let simulation = if use_simulation {
+        Some(compute_simulation(smaller,larger))
+    } else {
+        None
+    };
+    let shift = smaller.transitions.len();
+    // ***
+    let mut explored : Vec<MacroState<Letter>> = vec![];
+    // antichain of the identifiers of the minimal explored macro-states
+    let mut antichain : Vec<usize> = vec![];
+    let mut queue : VecDeque<usize> = VecDeque::new();
+    let mut initials : Vec<usize> = smaller.initials.iter().cloned().collect();
+    initials.sort();
+    for state in initials {
+        let macro_state = MacroState{state,states_set:larger.initials.iter().cloned().collect(),depth:0,origin:None};
+        add_if_not_subsumed(macro_state,&mut explored,&mut antichain,&mut queue,&simulation,shift);
+    }
+    // ***
+    while let Some(macro_id) = queue.pop_front() {
+        if !antichain.contains(&macro_id) {
+            // was subsumed by a macro-state found afterwards
+            continue;
+        }
+        let state = explored[macro_id].state;
+        if smaller.finals.contains(&state) && !explored[macro_id].states_set.iter().any(|q| larger.finals.contains(q)) {
+            return Some(get_word_of_macro_state(&explored,macro_id));
+        }
+        for letter in &letters {
+            if let Some(targets) = smaller.transitions[state].get(letter) {
+                let mut states_set = BTreeSet::new();
+                for larger_state in &explored[macro_id].states_set {
+                    if let Some(larger_targets) = larger.transitions[*larger_state].get(letter) {
+                        states_set.extend(larger_targets.iter().cloned());
+                    }
+                }
+                let depth = explored[macro_id].depth + 1;
+                let mut targets : Vec<usize> = targets.iter().cloned().collect();
+                targets.sort();
+                for targ in targets {
+                    let macro_state = MacroState{state:targ,states_set:states_set.clone(),depth,origin:Some((macro_id,*letter))};
+                    add_if_not_subsumed(macro_state,&mut explored,&mut antichain,&mut queue,&simulation,shift);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns None if *nfa* accepts all the words over its alphabet and otherwise a shortest word it does not accept (see "antichain_inclusion_counterexample").
+pub fn antichain_universality_counterexample<Letter : AutLetter>(nfa : &AutNFA<Letter>,
+                                                                 use_simulation : bool) -> Option<Vec<Letter>> {
+    antichain_inclusion_counterexample(&AutNFA::new_universal(nfa.alphabet.clone()),nfa,use_simulation)
+}
+
+
+fn add_if_not_subsumed<Letter : AutLetter>(macro_state : MacroState<Letter>,
+                                           explored : &mut Vec<MacroState<Letter>>,
+                                           antichain : &mut Vec<usize>,
+                                           queue : &mut VecDeque<usize>,
+                                           simulation : &Option<Vec<Vec<bool>>>,
+                                           shift : usize) {
+    if let Some(sim) = simulation {
+        if macro_state.states_set.iter().any(|q| sim[macro_state.state][q + shift]) {
+            return;
+        }
+    }
+    if antichain.iter().any(|other| is_subsumed(&macro_state,&explored[*other],simulation,shift)) {
+        return;
+    }
+    // only macro-states reached with words of the same length are removed so that the counterexample which is found is a shortest one
+    antichain.retain(|other| explored[*other].depth < macro_state.depth || !is_subsumed(&explored[*other],&macro_state,simulation,shift));
+    let macro_id = explored.len();
+    explored.push(macro_state);
+    antichain.push(macro_id);
+    queue.push_back(macro_id);
+}
+
+/// (p,P) is subsumed by (r,R) if any counterexample found from (p,P) would also be found from (r,R)
+fn is_subsumed<Letter : AutLetter>(subsumed : &MacroState<Letter>,
+                                   by : &MacroState<Letter>,
+                                   simulation : &Option<Vec<Vec<bool>>>,
+                                   shift : usize) -> bool {
+    match simulation {
+        None => {
+            subsumed.state == by.state && by.states_set.is_subset(&subsumed.states_set)
+        },
+        Some(sim) => {
+            sim[subsumed.state][by.state]
+                && by.states_set.iter().all(|r| subsumed.states_set.iter().any(|p| sim[r + shift][p + shift]))
+        }
+    }
+}
+
+fn get_word_of_macro_state<Letter : AutLetter>(explored : &[MacroState<Letter>],
+                                               macro_id : usize) -> Vec<Letter> {
+    let mut word = vec![];
+    let mut current = macro_id;
+    while let Some((previous,letter)) = explored[current].origin {
+        word.push(letter);
+        current = previous;
+    }
+    word.reverse();
+    word
+}
+
+
+/// Computes the maximal forward simulation on the disjoint union of both NFA,
+/// the states of *right* being shifted after those of *left*.
+/// "sim[x][y]" is true if y simulates x i.e. if x is final then y is final and
+/// each transition of x can be matched by a transition of y with the same letter towards a state simulating its target.
+fn compute_simulation<Letter : AutLetter>(left : &AutNFA<Letter>,
+                                          right : &AutNFA<Letter>) -> Vec<Vec<bool>> {
+    let shift = left.transitions.len();
+    let num_states = shift + right.transitions.len();
+    let letters : HashSet<Letter> = left.alphabet.union(&right.alphabet).cloned().collect();
+    let is_final = |x : usize| if x < shift {left.finals.contains(&x)} else {right.finals.contains(&(x - shift))};
+    let targets = |x : usize, letter : &Letter| -> Vec<usize> {
+        if x < shift {
+            left.transitions[x].get(letter).map(|t| t.iter().cloned().collect()).unwrap_or_default()
+        } else {
+            right.transitions[x - shift].get(letter).map(|t| t.iter().map(|y| y + shift).collect()).unwrap_or_default()
+        }
+    };
+    // ***
+    let mut sim = vec![vec![true;num_states];num_states];
+    for (x,row) in sim.iter_mut().enumerate() {
+        if is_final(x) {
+            for (y,cell) in row.iter_mut().enumerate() {
+                *cell = is_final(y);
+            }
+        }
+    }
+    // naive refinement until a fixpoint is reached
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for x in 0..num_states {
+            for y in 0..num_states {
+                if x == y || !sim[x][y] {
+                    continue;
+                }
+                let refuted = letters.iter().any(|letter| {
+                    let y_targets = targets(y,letter);
+                    targets(x,letter).iter().any(|x_targ| !y_targets.iter().any(|y_targ| sim[*x_targ][*y_targ]))
+                });
+                if refuted {
+                    sim[x][y] = false;
+                    changed = true;
+                }
+            }
+        }
+    }
+    sim
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::nfa::algos::antichain::{antichain_inclusion_counterexample, antichain_universality_counterexample};
+    use crate::nfa::nfa::AutNFA;
+    use crate::traits::run::AutRunnable;
+
+    /// accepts the words over {a,b} whose third letter from the end is 'a'
+    fn get_third_from_end_is_a() -> AutNFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{0,1}, 'b' => hashset!{0}},
+            hashmap!{'a' => hashset!{2}, 'b' => hashset!{2}},
+            hashmap!{'a' => hashset!{3}, 'b' => hashset!{3}},
+            hashmap!{}
+        ];
+        AutNFA::<char>::from_raw(alphabet, hashset!{0}, hashset!{3}, transitions).unwrap()
+    }
+
+    /// accepts the words over {a,b} whose third or second letter from the end is 'a'
+    fn get_third_or_second_from_end_is_a() -> AutNFA::<char> {
+        let mut nfa = get_third_from_end_is_a();
+        nfa.transitions[0].get_mut(&'a').unwrap().insert(2);
+        nfa
+    }
+
+    #[test]
+    fn antichain_inclusion_test() {
+        let smaller = get_third_from_end_is_a();
+        let larger = get_third_or_second_from_end_is_a();
+        for use_simulation in [false,true] {
+            assert_eq!(antichain_inclusion_counterexample(&smaller,&larger,use_simulation), None);
+            assert_eq!(antichain_inclusion_counterexample(&larger,&larger,use_simulation), None);
+            let word = antichain_inclusion_counterexample(&larger,&smaller,use_simulation).unwrap();
+            assert_eq!(word, vec!['a','b']);
+            assert!(larger.runs_trace(&word).unwrap());
+            assert!(!smaller.runs_trace(&word).unwrap());
+        }
+    }
+
+    #[test]
+    fn antichain_universality_test() {
+        let mut nfa = get_third_from_end_is_a();
+        for use_simulation in [false,true] {
+            assert_eq!(antichain_universality_counterexample(&nfa,use_simulation), Some(vec![]));
+        }
+        // states 4 to 6 also accept the words whose third letter from the end is 'b'
+        nfa.transitions[0].get_mut(&'b').unwrap().insert(4);
+        nfa.transitions.push(hashmap!{'a' => hashset!{5}, 'b' => hashset!{5}});
+        nfa.transitions.push(hashmap!{'a' => hashset!{6}, 'b' => hashset!{6}});
+        nfa.transitions.push(hashmap!{});
+        // states 7 to 9 also accept the words of length less than 3
+        nfa.transitions.push(hashmap!{'a' => hashset!{8}, 'b' => hashset!{8}});
+        nfa.transitions.push(hashmap!{'a' => hashset!{9}, 'b' => hashset!{9}});
+        nfa.transitions.push(hashmap!{});
+        nfa.initials.insert(7);
+        nfa.finals.extend([6,7,8,9]);
+        for use_simulation in [false,true] {
+            assert_eq!(antichain_universality_counterexample(&nfa,use_simulation), None);
+        }
+        // words of length 2 are no longer accepted
+        nfa.finals.remove(&9);
+        for use_simulation in [false,true] {
+            let word = antichain_universality_counterexample(&nfa,use_simulation).unwrap();
+            assert_eq!(word.len(), 2);
+            assert!(!nfa.runs_trace(&word).unwrap());
+        }
+    }
+}
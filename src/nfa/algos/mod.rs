@@ -15,3 +15,4 @@ limitations under the License.
 */
 
 pub mod kameda_weiner;
+pub mod antichain;
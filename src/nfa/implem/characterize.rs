@@ -15,12 +15,28 @@ limitations under the License.
 */
 
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::hash_map::Entry;
 
+use crate::nfa::algos::antichain::{antichain_inclusion_counterexample, antichain_universality_counterexample};
 use crate::nfa::nfa::AutNFA;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::letter::AutLetter;
-use crate::traits::transform::AutTransformable;
+
+
+impl<Letter : AutLetter> AutNFA<Letter> {
+
+    /// Returns None if each word accepted by *other* is also accepted by *self* and otherwise a word accepted by *other* but not by *self* (see "antichain_inclusion_counterexample")
+    pub fn get_antichain_inclusion_counterexample(&self, other : &Self, use_simulation : bool) -> Option<Vec<Letter>> {
+        antichain_inclusion_counterexample(other,self,use_simulation)
+    }
+
+    /// Returns None if *self* is universal and otherwise a word which it does not accept (see "antichain_universality_counterexample")
+    pub fn get_antichain_universality_counterexample(&self, use_simulation : bool) -> Option<Vec<Letter>> {
+        antichain_universality_counterexample(self,use_simulation)
+    }
+
+}
 
 impl<Letter : AutLetter> AutCharacterizable<Letter> for AutNFA<Letter> {
 
@@ -70,14 +86,96 @@ impl<Letter : AutLetter> AutCharacterizable<Letter> for AutNFA<Letter> {
         true
     }
 
+    // antichains
     fn is_universal(&self) -> bool {
-        self.clone().negate().is_empty()
+        antichain_universality_counterexample(self,false).is_none()
     }
 
+    // antichains
     fn contains(&self,
                 other: &Self) -> bool {
-        self.clone().negate().intersect(other.clone()).unwrap().is_empty()
+        antichain_inclusion_counterexample(other,self,false).is_none()
+    }
+
+    // breadth first search with sorted letters
+    fn find_accepted_word(&self) -> Option<Vec<Letter>> {
+        let mut letters : Vec<Letter> = self.alphabet.iter().cloned().collect();
+        letters.sort();
+        // for each reached state, the state and letter from which it is first reached
+        let mut reached_from : HashMap<usize,Option<(usize,Letter)>> = HashMap::new();
+        let mut initials : Vec<usize> = self.initials.iter().cloned().collect();
+        initials.sort();
+        let mut queue = VecDeque::new();
+        for initial in initials {
+            reached_from.insert(initial,None);
+            queue.push_back(initial);
+        }
+        // ***
+        while let Some(state) = queue.pop_front() {
+            if self.finals.contains(&state) {
+                let mut word = vec![];
+                let mut current = state;
+                while let Some((previous,letter)) = reached_from.get(&current).unwrap() {
+                    word.push(*letter);
+                    current = *previous;
+                }
+                word.reverse();
+                return Some(word);
+            }
+            for letter in &letters {
+                if let Some(targets) = self.transitions[state].get(letter) {
+                    let mut targets : Vec<usize> = targets.iter().cloned().collect();
+                    targets.sort();
+                    for target in targets {
+                        if let Entry::Vacant(entry) = reached_from.entry(target) {
+                            entry.insert(Some((state,*letter)));
+                            queue.push_back(target);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn find_rejected_word(&self) -> Option<Vec<Letter>> {
+        antichain_universality_counterexample(self,false)
+    }
+
+    fn find_inclusion_counterexample(&self, other: &Self) -> Option<Vec<Letter>> {
+        antichain_inclusion_counterexample(other,self,false)
     }
 
 }
 
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::nfa::nfa::AutNFA;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::{check_witness_words, WITNESS_TEST_LANGUAGES};
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn nfa_witness_words_test() {
+        let alphabet = hashset!{'a','b'};
+        let languages : Vec<AutNFA<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap().to_nfa())
+            .collect();
+        for aut1 in &languages {
+            for aut2 in &languages {
+                check_witness_words(aut1,aut2,&|aut,word| aut.runs_trace(word).unwrap(),4);
+            }
+        }
+        // no witness for the empty language, the universal language and included languages
+        assert_eq!(languages[0].find_accepted_word(), None);
+        assert_eq!(languages[1].find_rejected_word(), None);
+        assert_eq!(languages[4].find_inclusion_counterexample(&languages[3]), None);
+        assert_eq!(languages[1].find_inclusion_counterexample(&languages[6]), None);
+        assert_eq!(languages[3].find_inclusion_counterexample(&languages[4]).map(|word| word.len()), Some(3));
+        assert_eq!(languages[6].find_rejected_word(), Some(vec!['b','a']));
+    }
+
+}
@@ -21,4 +21,7 @@ mod transform;
 mod to_dot;
 mod run;
 mod translate;
-mod letter;
\ No newline at end of file
+mod letter;
+mod enumerate;
+#[cfg(feature = "serde")]
+pub(crate) mod serialize;
@@ -0,0 +1,127 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::nfa::nfa::AutNFA;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "initials":[state,..], "finals":[state,..], "transitions":[[[letter,[target,..]],..],..]}
+/// where "transitions" lists, for each state, its outgoing transitions sorted by letter
+#[derive(Serialize, Deserialize)]
+struct NFALayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    initials : BTreeSet<usize>,
+    finals : BTreeSet<usize>,
+    transitions : Vec<Vec<(Letter,BTreeSet<usize>)>>
+}
+
+/// Lists the outgoing transitions of each state sorted by letter
+pub(crate) fn transitions_to_layout<Letter : AutLetter>(transitions : &[HashMap<Letter,HashSet<usize>>]) -> Vec<Vec<(Letter,BTreeSet<usize>)>> {
+    transitions.iter()
+        .map(|map| map.iter()
+            .filter(|(_,targets)| !targets.is_empty())
+            .map(|(letter,targets)| (*letter,targets.iter().cloned().collect()))
+            .sorted()
+            .collect())
+        .collect()
+}
+
+/// Gathers the targets of the transitions of each state, rejecting several entries with the same letter from the same state
+pub(crate) fn transitions_from_layout<Letter : AutLetter>(layout : Vec<Vec<(Letter,BTreeSet<usize>)>>) -> Result<Vec<HashMap<Letter,HashSet<usize>>>,AutError<Letter>> {
+    let mut transitions = vec![];
+    for (orig,outgoing) in layout.into_iter().enumerate() {
+        let map_len = outgoing.len();
+        let map : HashMap<Letter,HashSet<usize>> = outgoing.into_iter()
+            .map(|(letter,targets)| (letter,targets.into_iter().collect()))
+            .collect();
+        if map.len() != map_len {
+            return Err(AutError::Other(format!("several transitions with the same letter from state {:}", orig)));
+        }
+        transitions.push(map);
+    }
+    Ok(transitions)
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for AutNFA<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NFALayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            initials : self.initials.iter().cloned().collect(),
+            finals : self.finals.iter().cloned().collect(),
+            transitions : transitions_to_layout(&self.transitions)
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for AutNFA<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = NFALayout::<Letter>::deserialize(deserializer)?;
+        AutNFA::from_raw(layout.alphabet.into_iter().collect(),
+                         layout.initials.into_iter().collect(),
+                         layout.finals.into_iter().collect(),
+                         transitions_from_layout(layout.transitions).map_err(D::Error::custom)?).map_err(D::Error::custom)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::nfa::nfa::AutNFA;
+
+    /// accepts the words over {a,b} which end with 'a'
+    fn get_example() -> AutNFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{0,1}, 'b' => hashset!{0}},
+            hashmap!{}
+        ];
+        AutNFA::<char>::from_raw(alphabet, hashset!{0}, hashset!{1}, transitions).unwrap()
+    }
+
+    #[test]
+    fn nfa_serialization_test() {
+        let nfa = get_example();
+        let json = serde_json::to_string(&nfa).unwrap();
+        assert_eq!(json, r#"{"alphabet":["a","b"],"initials":[0],"finals":[1],"transitions":[[["a",[0,1]],["b",[0]]],[]]}"#);
+        let got : AutNFA<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.initials, nfa.initials);
+        assert_eq!(got.finals, nfa.finals);
+        assert_eq!(got.transitions, nfa.transitions);
+    }
+
+    #[test]
+    fn nfa_deserialization_errors_test() {
+        // several entries with the same letter from the same state
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[["a",[0]],["a",[1]]],[]]}"#;
+        assert!(serde_json::from_str::<AutNFA<char>>(invalid).is_err());
+        // the target of a transition is not a state
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[["a",[1]]]]}"#;
+        assert!(serde_json::from_str::<AutNFA<char>>(invalid).is_err());
+        // a letter which is not in the alphabet
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[["b",[0]]]]}"#;
+        assert!(serde_json::from_str::<AutNFA<char>>(invalid).is_err());
+    }
+}
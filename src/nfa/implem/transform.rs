@@ -15,13 +15,13 @@ limitations under the License.
 */
 
 use std::collections::{HashMap, HashSet};
-use maplit::{hashmap, hashset};
+use maplit::hashmap;
 use crate::nfa::algos::kameda_weiner::algo::kameda_weiner_algorithm;
 
 use crate::nfa::nfa::AutNFA;
+use crate::nfait::algos::product::{shuffle_product, synchronous_product};
 use crate::traits::access::AutAccessible;
 use crate::traits::transform::AutTransformable;
-use crate::traits::build::AutBuildable;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::error::AutError;
 use crate::traits::letter::AutLetter;
@@ -83,76 +83,29 @@ impl<Letter: AutLetter> AutTransformable<Letter> for AutNFA<Letter> {
         }
     }
 
-    // De Morgan
+    // product of the accessible pairs of states
     fn intersect(self,
                  other: Self) -> Result<Self,AutError<Letter>> {
-        match self.negate().unite(other.negate()) {
+        let product = synchronous_product(&self.to_nfait(),&other.to_nfait(),&|l,r| l && r)?;
+        Ok(product.to_nfa())
+    }
+
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        // other must be a complete DFA so that each word leads to exactly one of its states
+        let other = other.to_dfa().complete();
+        let product = synchronous_product(&self.to_nfait(),&other.to_nfait(),&|l,r| l && !r)?;
+        Ok(product.to_nfa())
+    }
+
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_dfa().symmetric_difference(other.to_dfa()) {
             Err(e) => {Err(e)},
-            Ok(got) => {Ok(got.negate())}
+            Ok(got) => {Ok(got.to_nfa())}
         }
     }
 
     fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
-        if self.alphabet != other.alphabet {
-            return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(self.alphabet,other.alphabet));
-        }
-        let mut new_initials : HashSet<usize> = hashset! {};
-        let mut new_finals : HashSet<usize> = hashset! {};
-        let cross_states_map : Vec<(usize,usize)> = {
-            let mut csm = vec![];
-            for x in 0..self.transitions.len() {
-                for y in 0..other.transitions.len() {
-                    let index = csm.len();
-                    csm.push((x,y));
-                    if self.initials.contains(&x) && other.initials.contains(&y) {
-                        new_initials.insert(index);
-                    }
-                    if self.finals.contains(&x) && other.finals.contains(&y) {
-                        new_finals.insert(index);
-                    }
-                }
-            }
-            csm
-        };
-        let mut new_transitions : Vec<HashMap<Letter, HashSet<usize>>>= vec![];
-        for _ in 0..cross_states_map.len() {
-            new_transitions.push(hashmap!{});
-        }
-        for y in 0..other.transitions.len() {
-            for (x_orig,transitions) in self.transitions.iter().enumerate() {
-                let new_orig = cross_states_map.iter().position(|&r| r == (x_orig,y)).unwrap();
-                let outgoing = new_transitions.get_mut(new_orig).unwrap();
-                for (letter,x_targets) in transitions {
-                    let mut letter_map = match outgoing.remove(letter) {
-                        None => {hashset!{}},
-                        Some(got) => {got}
-                    };
-                    for x_targ in x_targets {
-                        let new_targ = cross_states_map.iter().position(|&r| r == (*x_targ,y)).unwrap();
-                        letter_map.insert(new_targ);
-                    }
-                    outgoing.insert(*letter,letter_map);
-                }
-            }
-        }
-        for x in 0..self.transitions.len() {
-            for (y_orig,transitions) in other.transitions.iter().enumerate() {
-                let new_orig = cross_states_map.iter().position(|&r| r == (x,y_orig)).unwrap();
-                let outgoing = new_transitions.get_mut(new_orig).unwrap();
-                for (letter,y_targets) in transitions {
-                    let mut letter_map = match outgoing.remove(letter) {
-                        None => {hashset!{}},
-                        Some(got) => {got}
-                    };
-                    for y_targ in y_targets {
-                        let new_targ = cross_states_map.iter().position(|&r| r == (x,*y_targ)).unwrap();
-                        letter_map.insert(new_targ);
-                    }
-                    outgoing.insert(*letter,letter_map);
-                }
-            }
-        }
-        // ***
-        Self::from_raw(self.alphabet,new_initials,new_finals,new_transitions)
+        let product = shuffle_product(&self.to_nfait(),&other.to_nfait())?;
+        Ok(product.to_nfa())
     }
 }
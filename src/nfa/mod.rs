@@ -16,5 +16,5 @@ limitations under the License.
 
 
 pub mod nfa;
-mod implem;
+pub(crate) mod implem;
 pub mod algos;
\ No newline at end of file
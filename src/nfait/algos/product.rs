@@ -0,0 +1,211 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashMap, HashSet};
+
+use crate::nfait::nfait::AutNFAIT;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// Returns the synchronous product of *left* and *right* in which both automata read each letter together
+/// while immediate transitions are taken independently.
+/// Only the pairs of states which are reachable from pairs of initial states are built.
+/// A pair of states is final if and only if *acceptance* holds for the finality of its two components.
+///
+/// The product accepts a word if and only if there are runs of both automata on that word
+/// which end in a pair satisfying *acceptance*.
+/// With a conjunction this is the intersection of the two languages for any operands.
+/// For other boolean combinations (union, difference etc.), the operands must be complete DFAs
+/// so that each word leads to exactly one pair of states.
+pub fn synchronous_product<Letter : AutLetter>(left : &AutNFAIT<Letter>,
+                                               right : &AutNFAIT<Letter>,
+                                               acceptance : &dyn Fn(bool,bool) -> bool)
+            -> Result<AutNFAIT<Letter>,AutError<Letter>> {
+    explore_product(left,right,acceptance,true)
+}
+
+/// Returns the shuffle product of *left* and *right* in which each letter is read by only one of the two automata.
+/// Only the pairs of states which are reachable from pairs of initial states are built.
+/// The product accepts exactly the interleavings of a word accepted by *left* and of a word accepted by *right*.
+pub fn shuffle_product<Letter : AutLetter>(left : &AutNFAIT<Letter>,
+                                           right : &AutNFAIT<Letter>)
+            -> Result<AutNFAIT<Letter>,AutError<Letter>> {
+    explore_product(left,right,&|l,r| l && r,false)
+}
+
+
+struct ProductStates {
+    pairs : Vec<(usize,usize)>,
+    pairs_ids : HashMap<(usize,usize),usize>
+}
+
+impl ProductStates {
+    fn get_or_insert(&mut self, pair : (usize,usize)) -> usize {
+        match self.pairs_ids.get(&pair) {
+            Some(id) => *id,
+            None => {
+                let id = self.pairs.len();
+                self.pairs.push(pair);
+                self.pairs_ids.insert(pair,id);
+                id
+            }
+        }
+    }
+}
+
+fn explore_product<Letter : AutLetter>(left : &AutNFAIT<Letter>,
+                                       right : &AutNFAIT<Letter>,
+                                       acceptance : &dyn Fn(bool,bool) -> bool,
+                                       synchronous : bool)
+            -> Result<AutNFAIT<Letter>,AutError<Letter>> {
+    if left.alphabet != right.alphabet {
+        return Err(AutError::OperationOnLanguagesOverDifferentAlphabets(left.alphabet.clone(),
+                                                                        right.alphabet.clone()));
+    }
+    // letters and initial states are sorted so that the numbering of the pairs is deterministic
+    let mut letters : Vec<Letter> = left.alphabet.iter().cloned().collect();
+    letters.sort();
+    let mut left_initials : Vec<usize> = left.initials.iter().cloned().collect();
+    left_initials.sort();
+    let mut right_initials : Vec<usize> = right.initials.iter().cloned().collect();
+    right_initials.sort();
+    // ***
+    let mut states = ProductStates{pairs:vec![],pairs_ids:HashMap::new()};
+    let mut initials = HashSet::new();
+    for l in &left_initials {
+        for r in &right_initials {
+            initials.insert(states.get_or_insert((*l,*r)));
+        }
+    }
+    // ***
+    let mut finals = HashSet::new();
+    let mut transitions : Vec<HashMap<Letter,HashSet<usize>>> = vec![];
+    let mut epsilon_trans : Vec<HashSet<usize>> = vec![];
+    // pairs are explored in the order in which they are discovered
+    let mut next_to_explore = 0;
+    while next_to_explore < states.pairs.len() {
+        let orig_id = next_to_explore;
+        next_to_explore += 1;
+        let (l,r) = states.pairs[orig_id];
+        if acceptance(left.finals.contains(&l), right.finals.contains(&r)) {
+            finals.insert(orig_id);
+        }
+        // ***
+        let mut outgoing : HashMap<Letter,HashSet<usize>> = HashMap::new();
+        for letter in &letters {
+            let left_targets = left.transitions[l].get(letter);
+            let right_targets = right.transitions[r].get(letter);
+            let mut targets = HashSet::new();
+            if synchronous {
+                if let (Some(left_targets),Some(right_targets)) = (left_targets,right_targets) {
+                    for lt in left_targets {
+                        for rt in right_targets {
+                            targets.insert(states.get_or_insert((*lt,*rt)));
+                        }
+                    }
+                }
+            } else {
+                for lt in left_targets.into_iter().flatten() {
+                    targets.insert(states.get_or_insert((*lt,r)));
+                }
+                for rt in right_targets.into_iter().flatten() {
+                    targets.insert(states.get_or_insert((l,*rt)));
+                }
+            }
+            if !targets.is_empty() {
+                outgoing.insert(*letter,targets);
+            }
+        }
+        transitions.push(outgoing);
+        // ***
+        let mut epsilon_targets = HashSet::new();
+        for lt in left.epsilon_trans.get(l).into_iter().flatten() {
+            epsilon_targets.insert(states.get_or_insert((*lt,r)));
+        }
+        for rt in right.epsilon_trans.get(r).into_iter().flatten() {
+            epsilon_targets.insert(states.get_or_insert((l,*rt)));
+        }
+        epsilon_trans.push(epsilon_targets);
+    }
+    // ***
+    AutNFAIT::from_raw(left.alphabet.clone(),initials,finals,transitions,epsilon_trans)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::nfa::nfa::AutNFA;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::transform::AutTransformable;
+    use crate::traits::translate::AutTranslatable;
+    use crate::tests_utils::get_all_words;
+
+    /// accepts the words over {a,b} which contain "ab"
+    fn get_contains_ab() -> AutNFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{0,1}, 'b' => hashset!{0}},
+            hashmap!{'b' => hashset!{2}},
+            hashmap!{'a' => hashset!{2}, 'b' => hashset!{2}}
+        ];
+        AutNFA::<char>::from_raw(alphabet, hashset!{0}, hashset!{2}, transitions).unwrap()
+    }
+
+    /// accepts the words over {a,b} which end with "a"
+    fn get_ends_with_a() -> AutNFA::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{0,1}, 'b' => hashset!{0}},
+            hashmap!{}
+        ];
+        AutNFA::<char>::from_raw(alphabet, hashset!{0}, hashset!{1}, transitions).unwrap()
+    }
+
+    #[test]
+    fn boolean_products_test() {
+        let left = get_contains_ab();
+        let right = get_ends_with_a();
+        let intersection = left.clone().intersect(right.clone()).unwrap();
+        let difference = left.clone().difference(right.clone()).unwrap();
+        let sym_difference = left.to_dfa().symmetric_difference(right.to_dfa()).unwrap();
+        let nfait_intersection = left.to_nfait().intersect(right.to_nfait()).unwrap();
+        for word in get_all_words(6) {
+            let in_left = left.runs_trace(&word).unwrap();
+            let in_right = right.runs_trace(&word).unwrap();
+            assert_eq!(intersection.runs_trace(&word).unwrap(), in_left && in_right);
+            assert_eq!(nfait_intersection.runs_trace(&word).unwrap(), in_left && in_right);
+            assert_eq!(difference.runs_trace(&word).unwrap(), in_left && !in_right);
+            assert_eq!(sym_difference.runs_trace(&word).unwrap(), in_left != in_right);
+        }
+    }
+
+    #[test]
+    fn shuffle_product_test() {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let left = AutNFA::new_matching(alphabet.clone(), &['a','b']);
+        let right = AutNFA::new_matching(alphabet, &['b']);
+        let interleaved = left.interleave(right).unwrap();
+        let accepted : Vec<Vec<char>> = get_all_words(4).into_iter()
+            .filter(|w| interleaved.runs_trace(w).unwrap())
+            .collect();
+        assert_eq!(accepted, vec![vec!['a','b','b'],vec!['b','a','b']]);
+    }
+}
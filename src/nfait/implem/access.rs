@@ -30,9 +30,10 @@ impl <Letter: AutLetter> AutNFAIT<Letter> {
         let mut to_iter: Vec<usize> = states.iter().cloned().collect();
         while let Some(next) = to_iter.pop() {
             if !closure.contains(&next) {
-                let next_targets : &HashSet<usize> = self.epsilon_trans.get(next).unwrap();
-                let mut as_target_vec : Vec<usize> = next_targets.iter().cloned().collect();
-                to_iter.append( &mut as_target_vec);
+                // the epsilon transitions of the last states may be omitted
+                if let Some(next_targets) = self.epsilon_trans.get(next) {
+                    to_iter.extend(next_targets.iter().cloned());
+                }
                 closure.insert(next);
             }
         }
@@ -83,6 +84,8 @@ impl<Letter: AutLetter> AutAccessible for AutNFAIT<Letter> {
     fn make_accessible(mut self) -> Self {
         let set_of_accessible_states = self.get_all_accessible_states();
         // ***
+        // the epsilon transitions of the last states may be omitted
+        self.epsilon_trans.resize(self.transitions.len(), hashset!{});
         let mut states_substitution = HashMap::new();
         let mut current_state_index = 0;
         let l = self.transitions.len();
@@ -115,6 +118,9 @@ impl<Letter: AutLetter> AutAccessible for AutNFAIT<Letter> {
                 }*/
             }
         }
+        for target_states in &mut self.epsilon_trans {
+            *target_states = target_states.iter().map(|target| *states_substitution.get(target).unwrap()).collect();
+        }
         // ***
         self
     }
@@ -126,7 +132,7 @@ impl<Letter: AutLetter> AutAccessible for AutNFAIT<Letter> {
     fn get_all_coaccessible_states(&self) -> HashSet<usize> {
         let mut targets_of : Vec<HashSet<usize>> = vec![];
         for orig_state in 0..self.transitions.len() {
-            let mut targets_of_orig : HashSet<usize> = self.epsilon_trans.get(orig_state).unwrap().clone();
+            let mut targets_of_orig : HashSet<usize> = self.epsilon_trans.get(orig_state).cloned().unwrap_or_default();
             for lit_targs in self.transitions.get(orig_state).unwrap().values() {
                 targets_of_orig.extend(lit_targs.iter().cloned());
             }
@@ -159,4 +165,52 @@ impl<Letter: AutLetter> AutAccessible for AutNFAIT<Letter> {
     fn trim(self) -> Self {
         self.make_accessible().make_coaccessible()
     }
-}
\ No newline at end of file
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+    use crate::nfait::nfait::AutNFAIT;
+    use crate::traits::access::AutAccessible;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn nfait_make_accessible_renumbers_epsilon_targets_test() {
+        // accepts "a" and "b" with an inaccessible state 0
+        let nfait = AutNFAIT::from_raw(hashset!{'a','b'},
+                                       hashset!{1},
+                                       hashset!{2},
+                                       vec![hashmap!{'a' => hashset!{2}},
+                                            hashmap!{'a' => hashset!{2}},
+                                            hashmap!{},
+                                            hashmap!{'b' => hashset!{2}}],
+                                       vec![hashset!{1},hashset!{3},hashset!{},hashset!{}]).unwrap();
+        let accessible = nfait.clone().make_accessible();
+        assert_eq!(accessible.transitions.len(), 3);
+        assert_eq!(accessible.epsilon_trans, vec![hashset!{2},hashset!{},hashset!{}]);
+        assert!(accessible.is_accessible());
+        assert!(accessible.runs_trace(&['b']).unwrap());
+        assert!(accessible.to_dfa().equals(&nfait.to_dfa()));
+    }
+
+    #[test]
+    fn nfait_short_epsilon_transitions_test() {
+        // no epsilon transitions are given for the last states
+        let nfait = AutNFAIT::from_raw(hashset!{'a','b'},
+                                       hashset!{1},
+                                       hashset!{2},
+                                       vec![hashmap!{'a' => hashset!{2}},
+                                            hashmap!{'a' => hashset!{2}},
+                                            hashmap!{},
+                                            hashmap!{'b' => hashset!{2}}],
+                                       vec![hashset!{1},hashset!{3}]).unwrap();
+        assert_eq!(nfait.get_all_coaccessible_states(), hashset!{0,1,2,3});
+        let accessible = nfait.clone().make_accessible();
+        assert_eq!(accessible.epsilon_trans, vec![hashset!{2},hashset!{},hashset!{}]);
+        assert!(accessible.to_dfa().equals(&nfait.to_dfa()));
+    }
+}
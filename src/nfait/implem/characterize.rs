@@ -15,11 +15,29 @@ limitations under the License.
 */
 
 
+use crate::nfa::algos::antichain::{antichain_inclusion_counterexample, antichain_universality_counterexample};
 use crate::nfait::nfait::AutNFAIT;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::letter::AutLetter;
 use crate::traits::translate::AutTranslatable;
 
+
+impl<Letter : AutLetter> AutNFAIT<Letter> {
+
+    /// Returns None if each word accepted by *other* is also accepted by *self* and otherwise a word accepted by *other* but not by *self* (see "antichain_inclusion_counterexample")
+    pub fn get_antichain_inclusion_counterexample(&self, other : &Self, use_simulation : bool) -> Option<Vec<Letter>> {
+        antichain_inclusion_counterexample(&other.remove_epsilon_transitions(),
+                                           &self.remove_epsilon_transitions(),
+                                           use_simulation)
+    }
+
+    /// Returns None if *self* is universal and otherwise a word which it does not accept (see "antichain_universality_counterexample")
+    pub fn get_antichain_universality_counterexample(&self, use_simulation : bool) -> Option<Vec<Letter>> {
+        antichain_universality_counterexample(&self.remove_epsilon_transitions(),use_simulation)
+    }
+
+}
+
 impl<Letter : AutLetter> AutCharacterizable<Letter> for AutNFAIT<Letter> {
 
     fn is_complete(&self) -> bool {
@@ -45,10 +63,54 @@ impl<Letter : AutLetter> AutCharacterizable<Letter> for AutNFAIT<Letter> {
     }
 
     fn is_universal(&self) -> bool {
-        self.to_nfa().is_universal()
+        self.get_antichain_universality_counterexample(false).is_none()
     }
 
     fn contains(&self, other: &Self) -> bool {
-        self.to_nfa().contains(&other.to_nfa())
+        self.get_antichain_inclusion_counterexample(other,false).is_none()
     }
+
+    fn find_accepted_word(&self) -> Option<Vec<Letter>> {
+        self.remove_epsilon_transitions().find_accepted_word()
+    }
+
+    fn find_rejected_word(&self) -> Option<Vec<Letter>> {
+        self.get_antichain_universality_counterexample(false)
+    }
+
+    fn find_inclusion_counterexample(&self, other: &Self) -> Option<Vec<Letter>> {
+        self.get_antichain_inclusion_counterexample(other,false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::nfait::nfait::AutNFAIT;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::tests_utils::{check_witness_words, WITNESS_TEST_LANGUAGES};
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn nfait_witness_words_test() {
+        let alphabet = hashset!{'a','b'};
+        let languages : Vec<AutNFAIT<char>> = WITNESS_TEST_LANGUAGES.iter()
+            .map(|text| parse_bre(text,&alphabet).unwrap().to_nfait())
+            .collect();
+        for aut1 in &languages {
+            for aut2 in &languages {
+                check_witness_words(aut1,aut2,&|aut,word| aut.runs_trace(word).unwrap(),4);
+            }
+        }
+        // no witness for the empty language, the universal language and included languages
+        assert_eq!(languages[0].find_accepted_word(), None);
+        assert_eq!(languages[1].find_rejected_word(), None);
+        assert_eq!(languages[4].find_inclusion_counterexample(&languages[3]), None);
+        assert_eq!(languages[1].find_inclusion_counterexample(&languages[6]), None);
+        assert_eq!(languages[3].find_inclusion_counterexample(&languages[4]).map(|word| word.len()), Some(3));
+        assert_eq!(languages[6].find_rejected_word(), Some(vec!['b','a']));
+    }
+
 }
@@ -0,0 +1,30 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::nfait::nfait::AutNFAIT;
+use crate::dfa::algos::enumeration::ShortlexWordsIterator;
+use crate::traits::enumerate::AutEnumerable;
+use crate::traits::letter::AutLetter;
+use crate::traits::translate::AutTranslatable;
+
+
+impl<Letter: AutLetter> AutEnumerable<Letter> for AutNFAIT<Letter> {
+
+    fn accepted_words(&self) -> ShortlexWordsIterator<Letter> {
+        ShortlexWordsIterator::new(&self.to_dfa())
+    }
+
+}
@@ -22,4 +22,7 @@ mod transform;
 mod translate;
 mod to_dot;
 mod run;
-mod letter;
\ No newline at end of file
+mod letter;
+mod enumerate;
+#[cfg(feature = "serde")]
+mod serialize;
@@ -0,0 +1,109 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::nfa::implem::serialize::{transitions_from_layout, transitions_to_layout};
+use crate::nfait::nfait::AutNFAIT;
+use crate::traits::letter::AutLetter;
+
+
+/// {"alphabet":[letter,..], "initials":[state,..], "finals":[state,..], "transitions":[[[letter,[target,..]],..],..], "epsilon_trans":[[target,..],..]}
+/// where "transitions" lists, for each state, its outgoing transitions sorted by letter
+/// and "epsilon_trans" lists, for each state, the targets of its immediate transitions
+#[derive(Serialize, Deserialize)]
+struct NFAITLayout<Letter : AutLetter> {
+    alphabet : BTreeSet<Letter>,
+    initials : BTreeSet<usize>,
+    finals : BTreeSet<usize>,
+    transitions : Vec<Vec<(Letter,BTreeSet<usize>)>>,
+    epsilon_trans : Vec<BTreeSet<usize>>
+}
+
+impl<Letter : AutLetter + Serialize> Serialize for AutNFAIT<Letter> {
+    fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NFAITLayout{
+            alphabet : self.alphabet.iter().cloned().collect(),
+            initials : self.initials.iter().cloned().collect(),
+            finals : self.finals.iter().cloned().collect(),
+            transitions : transitions_to_layout(&self.transitions),
+            epsilon_trans : self.epsilon_trans.iter().map(|targets| targets.iter().cloned().collect()).collect()
+        }.serialize(serializer)
+    }
+}
+
+impl<'de, Letter : AutLetter + Deserialize<'de>> Deserialize<'de> for AutNFAIT<Letter> {
+    fn deserialize<D : Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = NFAITLayout::<Letter>::deserialize(deserializer)?;
+        AutNFAIT::from_raw(layout.alphabet.into_iter().collect(),
+                           layout.initials.into_iter().collect(),
+                           layout.finals.into_iter().collect(),
+                           transitions_from_layout(layout.transitions).map_err(D::Error::custom)?,
+                           layout.epsilon_trans.into_iter().map(|targets| targets.into_iter().collect()).collect()).map_err(D::Error::custom)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use maplit::{hashmap, hashset};
+    use crate::nfait::nfait::AutNFAIT;
+
+    /// accepts the words over {a,b} which end with 'a' or are empty
+    fn get_example() -> AutNFAIT::<char> {
+        let alphabet : HashSet<char> = hashset!{'a','b'};
+        let transitions: Vec<HashMap<char, HashSet<usize>>> = vec![
+            hashmap!{'a' => hashset!{0,1}, 'b' => hashset!{0}},
+            hashmap!{}
+        ];
+        let epsilon_trans = vec![hashset!{1}, hashset!{}];
+        AutNFAIT::<char>::from_raw(alphabet, hashset!{0}, hashset!{1}, transitions, epsilon_trans).unwrap()
+    }
+
+    #[test]
+    fn nfait_serialization_test() {
+        let nfait = get_example();
+        let json = serde_json::to_string(&nfait).unwrap();
+        assert_eq!(json, r#"{"alphabet":["a","b"],"initials":[0],"finals":[1],"transitions":[[["a",[0,1]],["b",[0]]],[]],"epsilon_trans":[[1],[]]}"#);
+        let got : AutNFAIT<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.initials, nfait.initials);
+        assert_eq!(got.finals, nfait.finals);
+        assert_eq!(got.transitions, nfait.transitions);
+        assert_eq!(got.epsilon_trans, nfait.epsilon_trans);
+        // states without immediate transitions may be omitted at the end of "epsilon_trans"
+        let short = r#"{"alphabet":["a","b"],"initials":[0],"finals":[1],"transitions":[[["a",[0,1]],["b",[0]]],[]],"epsilon_trans":[[1]]}"#;
+        let got : AutNFAIT<char> = serde_json::from_str(short).unwrap();
+        assert_eq!(got.epsilon_trans, vec![hashset!{1}]);
+    }
+
+    #[test]
+    fn nfait_deserialization_errors_test() {
+        // the target of an immediate transition is not a state
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[]],"epsilon_trans":[[1]]}"#;
+        assert!(serde_json::from_str::<AutNFAIT<char>>(invalid).is_err());
+        // more entries in "epsilon_trans" than there are states
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[]],"epsilon_trans":[[],[]]}"#;
+        assert!(serde_json::from_str::<AutNFAIT<char>>(invalid).is_err());
+        // several entries with the same letter from the same state
+        let invalid = r#"{"alphabet":["a"],"initials":[0],"finals":[],"transitions":[[["a",[0]],["a",[0]]]],"epsilon_trans":[]}"#;
+        assert!(serde_json::from_str::<AutNFAIT<char>>(invalid).is_err());
+    }
+}
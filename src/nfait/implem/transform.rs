@@ -15,10 +15,10 @@ limitations under the License.
 */
 
 use std::collections::{HashMap, HashSet};
-use maplit::hashmap;
+use maplit::{hashmap, hashset};
 
+use crate::nfait::algos::product::{shuffle_product, synchronous_product};
 use crate::nfait::nfait::AutNFAIT;
-use crate::traits::build::AutBuildable;
 use crate::traits::characterize::AutCharacterizable;
 use crate::traits::error::AutError;
 use crate::traits::letter::AutLetter;
@@ -70,7 +70,15 @@ impl<Letter: AutLetter> AutTransformable<Letter> for AutNFAIT<Letter> {
             }
         }
         // ***
+        let mut epsilon_trans = vec![hashset!{};self.transitions.len()];
+        for (origin_state, target_states) in self.epsilon_trans.iter().enumerate() {
+            for target_state in target_states {
+                epsilon_trans[*target_state].insert(origin_state);
+            }
+        }
+        // ***
         self.transitions = transitions;
+        self.epsilon_trans = epsilon_trans;
         std::mem::swap(&mut self.initials, &mut self.finals);
         // ***
         self
@@ -80,20 +88,57 @@ impl<Letter: AutLetter> AutTransformable<Letter> for AutNFAIT<Letter> {
         self.to_dfa().minimize().to_nfait()
     }
 
-    // De Morgan
+    // product of the accessible pairs of states
     fn intersect(self,
                  other: Self) -> Result<Self,AutError<Letter>> {
-        match self.negate().unite(other.negate()) {
-            Err(e) => {Err(e)},
-            Ok(got) => {Ok(got.negate())}
-        }
+        synchronous_product(&self,&other,&|l,r| l && r)
     }
 
-    fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
-        match self.to_nfa().interleave(other.to_nfa()) {
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        // other must be a complete DFA so that each word leads to exactly one of its states
+        let other = other.to_dfa().complete().to_nfait();
+        synchronous_product(&self,&other,&|l,r| l && !r)
+    }
+
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>> {
+        match self.to_dfa().symmetric_difference(other.to_dfa()) {
             Err(e) => {Err(e)},
             Ok(got) => {Ok(got.to_nfait())}
         }
     }
 
+    fn interleave(self, other: Self) -> Result<Self,AutError<Letter>> {
+        shuffle_product(&self,&other)
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+    use crate::nfait::nfait::AutNFAIT;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::run::AutRunnable;
+    use crate::traits::transform::AutTransformable;
+    use crate::traits::translate::AutTranslatable;
+
+    #[test]
+    fn nfait_reverse_epsilon_transitions_test() {
+        // accepts "ab" with an epsilon transition between the two letters
+        let nfait = AutNFAIT::from_raw(hashset!{'a','b'},
+                                       hashset!{0},
+                                       hashset!{3},
+                                       vec![hashmap!{'a' => hashset!{1}},
+                                            hashmap!{},
+                                            hashmap!{'b' => hashset!{3}},
+                                            hashmap!{}],
+                                       vec![hashset!{},hashset!{2}]).unwrap();
+        let reversed = nfait.clone().reverse();
+        assert_eq!(reversed.epsilon_trans[2], hashset!{1});
+        assert!(reversed.runs_trace(&['b','a']).unwrap());
+        assert!(!reversed.runs_trace(&['a','b']).unwrap());
+        assert!(reversed.to_dfa().equals(&nfait.to_dfa().reverse()));
+    }
 }
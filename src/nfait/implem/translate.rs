@@ -14,7 +14,7 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use maplit::{hashmap, hashset};
 
 use crate::bre::bre::ExpBRE;
@@ -26,6 +26,32 @@ use crate::nfait::nfait::AutNFAIT;
 use crate::traits::letter::AutLetter;
 use crate::traits::translate::AutTranslatable;
 
+impl<Letter : AutLetter> AutNFAIT<Letter> {
+
+    /// Returns a NFA with the same states which accepts the same language.
+    /// Each state gets the letter transitions of all the states in its epsilon closure
+    /// and it is final if its epsilon closure contains a final state.
+    pub fn remove_epsilon_transitions(&self) -> AutNFA<Letter> {
+        let mut finals = hashset!{};
+        let mut transitions = vec![];
+        for state in 0..self.transitions.len() {
+            let closure = self.get_epsilon_closure(&hashset!{state});
+            if closure.iter().any(|x| self.finals.contains(x)) {
+                finals.insert(state);
+            }
+            let mut outgoing : HashMap<Letter,HashSet<usize>> = hashmap!{};
+            for reached in closure {
+                for (letter,targets) in &self.transitions[reached] {
+                    outgoing.entry(*letter).or_default().extend(targets.iter().cloned());
+                }
+            }
+            transitions.push(outgoing);
+        }
+        AutNFA::from_raw(self.alphabet.clone(),self.initials.clone(),finals,transitions).unwrap()
+    }
+
+}
+
 impl<Letter : AutLetter> AutTranslatable<Letter> for AutNFAIT<Letter> {
 
     fn to_dfa(&self) -> AutDFA<Letter> {
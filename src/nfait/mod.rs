@@ -17,4 +17,5 @@ limitations under the License.
 
 pub mod nfait;
 mod implem;
+pub mod algos;
 
@@ -0,0 +1,171 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::bre::bre::ExpBRE;
+use crate::bre::term::TermBRE;
+use crate::parsers::reader::{is_empty_symbol, is_epsilon_symbol, TextReader};
+use crate::traits::error::AutError;
+
+
+/// Parses a regular expression over *alphabet* written with the syntax of "CharAsLetterPrinter" e.g. "(ab|c)*d".
+///
+/// From the lowest to the highest precedence, the operators are the alternation "|",
+/// the concatenation (either implicit or with ".") and the Kleene star "*".
+/// "∅" and "𝜀" (or "ε") denote the empty language and the empty word, parentheses group sub-expressions,
+/// whitespaces are ignored and any other character is a letter.
+/// An empty expression (or sub-expression) denotes the empty word.
+///
+/// Errors are reported with the position (in characters) at which they occur.
+pub fn parse_bre(text : &str,
+                 alphabet : &HashSet<char>) -> Result<ExpBRE<char>,AutError<char>> {
+    let mut reader = TextReader::new(text);
+    let term = parse_union(&mut reader,alphabet)?;
+    if let Some(got) = reader.peek() {
+        let position = reader.position();
+        return Err(reader.error(position,format!("unexpected '{}'", got)));
+    }
+    ExpBRE::from_raw(alphabet.clone(),term)
+}
+
+fn parse_union(reader : &mut TextReader,
+               alphabet : &HashSet<char>) -> Result<TermBRE<char>,AutError<char>> {
+    let mut term = parse_concat(reader,alphabet)?;
+    while reader.accept('|') {
+        term = term.unite(parse_concat(reader,alphabet)?);
+    }
+    Ok(term)
+}
+
+fn parse_concat(reader : &mut TextReader,
+                alphabet : &HashSet<char>) -> Result<TermBRE<char>,AutError<char>> {
+    let mut term = TermBRE::Epsilon;
+    loop {
+        match reader.peek() {
+            None | Some('|') | Some(')') => {
+                return Ok(term);
+            },
+            Some('.') => {
+                reader.advance();
+            },
+            Some(_) => {
+                term = term.concatenate(parse_kleene(reader,alphabet)?);
+            }
+        }
+    }
+}
+
+fn parse_kleene(reader : &mut TextReader,
+                alphabet : &HashSet<char>) -> Result<TermBRE<char>,AutError<char>> {
+    let mut term = parse_atom(reader,alphabet)?;
+    while reader.accept('*') {
+        term = TermBRE::Kleene(Box::new(term));
+    }
+    Ok(term)
+}
+
+fn parse_atom(reader : &mut TextReader,
+              alphabet : &HashSet<char>) -> Result<TermBRE<char>,AutError<char>> {
+    let position = reader.position();
+    match reader.advance() {
+        Some('(') => {
+            let term = parse_union(reader,alphabet)?;
+            if !reader.accept(')') {
+                let end = reader.position();
+                return Err(reader.error(end,format!("missing ')' to close '(' at position {}", position)));
+            }
+            Ok(term)
+        },
+        Some('*') => {
+            Err(reader.error(position,"nothing to repeat with '*'".to_string()))
+        },
+        Some(got) if is_empty_symbol(got) => {
+            Ok(TermBRE::Empty)
+        },
+        Some(got) if is_epsilon_symbol(got) => {
+            Ok(TermBRE::Epsilon)
+        },
+        Some(got) => {
+            if alphabet.contains(&got) {
+                Ok(TermBRE::Literal(got))
+            } else {
+                Err(reader.error(position,format!("letter '{}' not in alphabet {:?}", got, alphabet)))
+            }
+        },
+        None => {
+            Err(reader.error(position,"unexpected end of expression".to_string()))
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::bre::bre::ExpBRE;
+    use crate::bre::term::TermBRE;
+    use crate::parsers::bre_parser::parse_bre;
+    use crate::printers::p_chars::CharAsLetterPrinter;
+    use crate::random::params::RandomTermParameters;
+    use crate::traits::characterize::AutCharacterizable;
+    use crate::traits::error::AutError;
+    use crate::traits::repr::ExpBREPrintable;
+    use crate::tests_utils::for_each_random_term;
+
+    #[test]
+    fn parse_bre_test() {
+        let alphabet = hashset!{'a','b','c','d'};
+        let got = parse_bre("(ab|c)*d",&alphabet).unwrap();
+        let ab_or_c = TermBRE::Union(BTreeSet::from([
+            TermBRE::Concat(vec![TermBRE::Literal('a'),TermBRE::Literal('b')]),
+            TermBRE::Literal('c')]));
+        let expected = TermBRE::Concat(vec![TermBRE::Kleene(Box::new(ab_or_c)),TermBRE::Literal('d')]);
+        assert_eq!(got.term, expected);
+        assert_eq!(parse_bre("a . b | 𝜀",&alphabet).unwrap().term,
+                   parse_bre("ab|ε",&alphabet).unwrap().term);
+        assert_eq!(parse_bre("∅",&alphabet).unwrap().term, TermBRE::Empty);
+    }
+
+    #[test]
+    fn parse_bre_errors_test() {
+        let alphabet = hashset!{'a','b'};
+        for (text,expected_position) in [("a(b|a",5),("ab)",2),("a|*b",2),("abx",2)] {
+            match parse_bre(text,&alphabet) {
+                Err(AutError::InvalidSyntax(position,_)) => {
+                    assert_eq!(position, expected_position);
+                },
+                _ => {
+                    panic!("expected a syntax error for {}", text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn print_then_parse_bre_test() {
+        let alphabet = hashset!{'a','b','c'};
+        let params = RandomTermParameters::new(alphabet.clone(),5,15);
+        for_each_random_term(16,50,&params,|term| {
+            let printed = term.regexp_to_string(false,&CharAsLetterPrinter{});
+            let parsed = parse_bre(&printed,&alphabet).unwrap();
+            let original = ExpBRE::from_raw(alphabet.clone(),term).unwrap();
+            assert!(original.equals(&parsed), "{}", printed);
+        });
+    }
+}
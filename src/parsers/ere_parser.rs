@@ -0,0 +1,333 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::ere::term::TermERE;
+use crate::parsers::reader::{is_empty_symbol, is_epsilon_symbol, TextReader};
+use crate::printers::commons::{SYNTAX_INTERSECTION_CLEAR, SYNTAX_NEGATION_CLEAR};
+use crate::traits::error::AutError;
+
+
+/// Parses an extended regular expression over *alphabet* written with the syntax of "CharAsLetterPrinter" e.g. "¬(a*)∩[a-c]{2,}".
+///
+/// From the lowest to the highest precedence, the operators are the alternation "|", the intersection "∩",
+/// the implicit concatenation, the negation "¬" (as a prefix) and the repetitions "*", "+", "?",
+/// "{m}", "{m,}", "{,n}" and "{m,n}" (as suffixes).
+/// "∅" and "𝜀" (or "ε") denote the empty language and the empty word, "." denotes any letter of *alphabet*
+/// and parentheses group sub-expressions.
+/// Character classes such as "[abc]", "[a-f]" or "[^x]" are expanded into unions of letters of *alphabet*
+/// (letters of a range which are not in *alphabet* are ignored).
+/// Whitespaces are ignored, any other character is a letter and
+/// an empty expression (or sub-expression) denotes the empty word.
+///
+/// Errors are reported with the position (in characters) at which they occur.
+pub fn parse_ere(text : &str,
+                 alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let mut reader = TextReader::new(text);
+    let term = parse_union(&mut reader,alphabet)?;
+    if let Some(got) = reader.peek() {
+        let position = reader.position();
+        return Err(reader.error(position,format!("unexpected '{}'", got)));
+    }
+    Ok(term)
+}
+
+fn is_intersection_symbol(got : char) -> bool {
+    SYNTAX_INTERSECTION_CLEAR.starts_with(got)
+}
+
+fn is_negation_symbol(got : char) -> bool {
+    SYNTAX_NEGATION_CLEAR.starts_with(got)
+}
+
+fn parse_union(reader : &mut TextReader,
+               alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let mut term = parse_intersection(reader,alphabet)?;
+    while reader.accept('|') {
+        term = term.unite(parse_intersection(reader,alphabet)?);
+    }
+    Ok(term)
+}
+
+fn parse_intersection(reader : &mut TextReader,
+                      alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let mut term = parse_concat(reader,alphabet)?;
+    while reader.peek().is_some_and(is_intersection_symbol) {
+        reader.advance();
+        term = term.intersect(parse_concat(reader,alphabet)?);
+    }
+    Ok(term)
+}
+
+fn parse_concat(reader : &mut TextReader,
+                alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let mut term = TermERE::Epsilon;
+    loop {
+        match reader.peek() {
+            None | Some('|') | Some(')') => {
+                return Ok(term);
+            },
+            Some(got) if is_intersection_symbol(got) => {
+                return Ok(term);
+            },
+            Some(_) => {
+                term = term.concatenate(parse_negation(reader,alphabet)?);
+            }
+        }
+    }
+}
+
+fn parse_negation(reader : &mut TextReader,
+                  alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    if reader.peek().is_some_and(is_negation_symbol) {
+        reader.advance();
+        let sub_term = parse_negation(reader,alphabet)?;
+        return Ok(TermERE::Negation(Box::new(sub_term)));
+    }
+    parse_repetition(reader,alphabet)
+}
+
+fn parse_repetition(reader : &mut TextReader,
+                    alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let mut term = parse_atom(reader,alphabet)?;
+    loop {
+        let (min,max) = match reader.peek() {
+            Some('*') => {
+                reader.advance();
+                (0,None)
+            },
+            Some('+') => {
+                reader.advance();
+                (1,None)
+            },
+            Some('?') => {
+                reader.advance();
+                (0,Some(1))
+            },
+            Some('{') => {
+                parse_bounds(reader)?
+            },
+            _ => {
+                return Ok(term);
+            }
+        };
+        term = TermERE::Repeat(Box::new(term),min,max);
+    }
+}
+
+/// Parses "{m}", "{m,}", "{,n}" or "{m,n}"
+fn parse_bounds(reader : &mut TextReader) -> Result<(usize,Option<usize>),AutError<char>> {
+    let position = reader.position();
+    reader.advance();
+    let min = parse_number(reader)?;
+    let max = if reader.accept(',') {
+        parse_number(reader)?
+    } else {
+        match min {
+            None => {
+                let got_position = reader.position();
+                return Err(reader.error(got_position,"expected a number or ','".to_string()));
+            },
+            Some(_) => min
+        }
+    };
+    if !reader.accept('}') {
+        let end = reader.position();
+        return Err(reader.error(end,format!("missing '}}' to close '{{' at position {}", position)));
+    }
+    let min = min.unwrap_or(0);
+    if let Some(max) = max {
+        if max < min {
+            return Err(reader.error(position,format!("invalid repetition bounds {} > {}", min, max)));
+        }
+    }
+    Ok((min,max))
+}
+
+fn parse_number(reader : &mut TextReader) -> Result<Option<usize>,AutError<char>> {
+    let position = reader.position();
+    let mut digits = String::new();
+    while let Some(got) = reader.peek() {
+        if !got.is_ascii_digit() {
+            break;
+        }
+        reader.advance();
+        digits.push(got);
+    }
+    if digits.is_empty() {
+        return Ok(None);
+    }
+    match digits.parse::<usize>() {
+        Ok(number) => Ok(Some(number)),
+        Err(_) => Err(reader.error(position,format!("invalid number '{}'", digits)))
+    }
+}
+
+fn parse_atom(reader : &mut TextReader,
+              alphabet : &HashSet<char>) -> Result<TermERE<char>,AutError<char>> {
+    let position = reader.position();
+    match reader.advance() {
+        Some('(') => {
+            let term = parse_union(reader,alphabet)?;
+            if !reader.accept(')') {
+                let end = reader.position();
+                return Err(reader.error(end,format!("missing ')' to close '(' at position {}", position)));
+            }
+            Ok(term)
+        },
+        Some('[') => {
+            parse_class(reader,alphabet,position)
+        },
+        Some('.') => {
+            Ok(TermERE::Wildcard)
+        },
+        Some(got) if ['*','+','?','{'].contains(&got) => {
+            Err(reader.error(position,format!("nothing to repeat with '{}'", got)))
+        },
+        Some(got) if ['}',']'].contains(&got) => {
+            Err(reader.error(position,format!("unexpected '{}'", got)))
+        },
+        Some(got) if is_empty_symbol(got) => {
+            Ok(TermERE::Empty)
+        },
+        Some(got) if is_epsilon_symbol(got) => {
+            Ok(TermERE::Epsilon)
+        },
+        Some(got) => {
+            check_letter(reader,alphabet,got,position)?;
+            Ok(TermERE::Literal(got))
+        },
+        None => {
+            Err(reader.error(position,"unexpected end of expression".to_string()))
+        }
+    }
+}
+
+fn check_letter(reader : &TextReader,
+                alphabet : &HashSet<char>,
+                letter : char,
+                position : usize) -> Result<(),AutError<char>> {
+    if alphabet.contains(&letter) {
+        Ok(())
+    } else {
+        Err(reader.error(position,format!("letter '{}' not in alphabet {:?}", letter, alphabet)))
+    }
+}
+
+/// Parses the rest of a character class opened at *opening* and returns the union of the letters it denotes
+fn parse_class(reader : &mut TextReader,
+               alphabet : &HashSet<char>,
+               opening : usize) -> Result<TermERE<char>,AutError<char>> {
+    let negated = reader.accept('^');
+    let mut letters : BTreeSet<char> = BTreeSet::new();
+    loop {
+        let position = reader.position();
+        match reader.advance() {
+            None => {
+                return Err(reader.error(position,format!("missing ']' to close '[' at position {}", opening)));
+            },
+            Some(']') => {
+                break;
+            },
+            Some(first) => {
+                if reader.accept('-') {
+                    let last_position = reader.position();
+                    match reader.advance() {
+                        Some(last) if last != ']' => {
+                            if last < first {
+                                return Err(reader.error(position,format!("invalid range '{}-{}'", first, last)));
+                            }
+                            letters.extend(alphabet.iter().filter(|letter| first <= **letter && **letter <= last));
+                        },
+                        _ => {
+                            return Err(reader.error(last_position,"missing end of range".to_string()));
+                        }
+                    }
+                } else {
+                    check_letter(reader,alphabet,first,position)?;
+                    letters.insert(first);
+                }
+            }
+        }
+    }
+    if negated {
+        letters = alphabet.iter().filter(|letter| !letters.contains(letter)).cloned().collect();
+    }
+    Ok(letters.into_iter().fold(TermERE::Empty,|term,letter| term.unite(TermERE::Literal(letter))))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use maplit::hashset;
+    use crate::ere::term::TermERE;
+    use crate::parsers::ere_parser::parse_ere;
+    use crate::printers::p_chars::CharAsLetterPrinter;
+    use crate::traits::error::AutError;
+    use crate::traits::repr::ExpBREPrintable;
+
+    #[test]
+    fn parse_ere_test() {
+        let alphabet = hashset!{'a','b','c','x'};
+        let a = TermERE::Literal('a');
+        let b = TermERE::Literal('b');
+        let c = TermERE::Literal('c');
+        let x = TermERE::Literal('x');
+        assert_eq!(parse_ere("[a-f]",&alphabet).unwrap(),
+                   TermERE::Union(BTreeSet::from([a.clone(),b.clone(),c.clone()])));
+        assert_eq!(parse_ere("[^x]",&alphabet).unwrap(),
+                   parse_ere("a|b|c",&alphabet).unwrap());
+        assert_eq!(parse_ere("a{2,3}b?",&alphabet).unwrap(),
+                   TermERE::Concat(vec![TermERE::Repeat(Box::new(a.clone()),2,Some(3)),
+                                        TermERE::Repeat(Box::new(b.clone()),0,Some(1))]));
+        let negation = TermERE::Negation(Box::new(TermERE::Repeat(Box::new(x.clone()),1,None)));
+        let wildcards = TermERE::Repeat(Box::new(TermERE::Wildcard),0,None);
+        assert_eq!(parse_ere("¬x+ ∩ .*",&alphabet).unwrap(),
+                   TermERE::Intersection(BTreeSet::from([negation,wildcards])));
+        assert_eq!(parse_ere("a{,2}|b{3}|c{1,}",&alphabet).unwrap(),
+                   TermERE::Union(BTreeSet::from([TermERE::Repeat(Box::new(a),0,Some(2)),
+                                                  TermERE::Repeat(Box::new(b),3,Some(3)),
+                                                  TermERE::Repeat(Box::new(c),1,None)])));
+    }
+
+    #[test]
+    fn parse_ere_errors_test() {
+        let alphabet = hashset!{'a','b'};
+        for (text,expected_position) in [("a{3,2}",1),("a{2",3),("[ab",3),("+a",0),("a[y]",2),("(a∩b",4)] {
+            match parse_ere(text,&alphabet) {
+                Err(AutError::InvalidSyntax(position,_)) => {
+                    assert_eq!(position, expected_position, "{}", text);
+                },
+                _ => {
+                    panic!("expected a syntax error for {}", text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn print_then_parse_ere_test() {
+        let alphabet = hashset!{'a','b','c'};
+        for text in ["(ab|c)*∩¬(a{2,})", "a?(b|c){1,3}", ".+|∅|𝜀", "¬(a.)∩b{,2}"] {
+            let term = parse_ere(text,&alphabet).unwrap();
+            let printed = term.regexp_to_string(false,&CharAsLetterPrinter{});
+            assert_eq!(parse_ere(&printed,&alphabet).unwrap(), term, "{}", printed);
+        }
+    }
+}
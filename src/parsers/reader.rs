@@ -0,0 +1,83 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::printers::commons::{SYNTAX_EMPTY_CLEAR, SYNTAX_EPSILON_CLEAR};
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// Reads a text character by character while skipping whitespaces
+/// and keeping track of the position (in characters) of the next character to read.
+pub(crate) struct TextReader {
+    chars : Vec<char>,
+    position : usize
+}
+
+impl TextReader {
+
+    pub fn new(text : &str) -> Self {
+        TextReader{chars:text.chars().collect(),position:0}
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while self.position < self.chars.len() && self.chars[self.position].is_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    /// Returns the position of the next character that is not a whitespace
+    pub fn position(&mut self) -> usize {
+        self.skip_whitespaces();
+        self.position
+    }
+
+    pub fn peek(&mut self) -> Option<char> {
+        self.skip_whitespaces();
+        self.chars.get(self.position).cloned()
+    }
+
+    pub fn advance(&mut self) -> Option<char> {
+        let got = self.peek();
+        if got.is_some() {
+            self.position += 1;
+        }
+        got
+    }
+
+    /// Consumes the next character if it is *expected*
+    pub fn accept(&mut self, expected : char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn error<Letter : AutLetter>(&self, position : usize, msg : String) -> AutError<Letter> {
+        AutError::InvalidSyntax(position, msg)
+    }
+
+}
+
+pub(crate) fn is_empty_symbol(got : char) -> bool {
+    SYNTAX_EMPTY_CLEAR.starts_with(got)
+}
+
+/// Both the mathematical epsilon of the clear syntax and the greek epsilon of the HTML syntax are accepted
+pub(crate) fn is_epsilon_symbol(got : char) -> bool {
+    SYNTAX_EPSILON_CLEAR.starts_with(got) || got == 'ε'
+}
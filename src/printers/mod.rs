@@ -15,6 +15,6 @@ limitations under the License.
 */
 
 
-mod commons;
+pub(crate) mod commons;
 pub mod p_chars;
 
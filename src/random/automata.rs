@@ -0,0 +1,190 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet};
+use rand::seq::SliceRandom;
+
+use crate::dfa::dfa::AutDFA;
+use crate::nfa::nfa::AutNFA;
+use crate::nfait::nfait::AutNFAIT;
+use crate::random::generator::{check_probability, AutRandomGenerator};
+use crate::random::params::RandomAutomatonParameters;
+use crate::traits::access::AutAccessible;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+impl AutRandomGenerator {
+
+    /// Returns a random DFA whose initial state is state 0
+    pub fn generate_dfa<Letter : AutLetter>(&mut self,
+                                            params : &RandomAutomatonParameters<Letter>) -> Result<AutDFA<Letter>,AutError<Letter>> {
+        let nfait = self.generate_automaton(params,true,false)?;
+        let transitions = nfait.transitions.into_iter()
+            .map(|map| map.into_iter().map(|(letter,targets)| (letter,*targets.iter().next().unwrap())).collect())
+            .collect();
+        AutDFA::from_raw(nfait.alphabet,0,nfait.finals,transitions)
+    }
+
+    pub fn generate_nfa<Letter : AutLetter>(&mut self,
+                                            params : &RandomAutomatonParameters<Letter>) -> Result<AutNFA<Letter>,AutError<Letter>> {
+        let nfait = self.generate_automaton(params,false,false)?;
+        AutNFA::from_raw(nfait.alphabet,nfait.initials,nfait.finals,nfait.transitions)
+    }
+
+    pub fn generate_nfait<Letter : AutLetter>(&mut self,
+                                              params : &RandomAutomatonParameters<Letter>) -> Result<AutNFAIT<Letter>,AutError<Letter>> {
+        self.generate_automaton(params,false,true)
+    }
+
+    fn generate_automaton<Letter : AutLetter>(&mut self,
+                                              params : &RandomAutomatonParameters<Letter>,
+                                              deterministic : bool,
+                                              with_epsilon : bool) -> Result<AutNFAIT<Letter>,AutError<Letter>> {
+        let states_num = params.states_num;
+        let initials_num = if deterministic {1} else {params.initials_num};
+        if states_num == 0 {
+            return Err(AutError::Other("cannot generate an automaton without states".to_string()));
+        }
+        if initials_num > states_num || params.finals_num > states_num {
+            return Err(AutError::Other(format!("cannot have more initial or final states than the {} states", states_num)));
+        }
+        check_probability("transition density",params.transition_density)?;
+        check_probability("epsilon density",params.epsilon_density)?;
+        // letters are sorted so that the generation only depends on the seed
+        let mut letters : Vec<Letter> = params.alphabet.iter().cloned().collect();
+        letters.sort();
+        // ***
+        // the initial states come first in a random order of the states, state 0 being initial
+        let mut order : Vec<usize> = (1..states_num).collect();
+        order.shuffle(&mut self.rng);
+        order.insert(0,0);
+        let initials : HashSet<usize> = order[..initials_num].iter().cloned().collect();
+        let mut transitions : Vec<HashMap<Letter,HashSet<usize>>> = vec![HashMap::new();states_num];
+        if (params.ensure_accessible || params.ensure_trimmed) && initials_num > 0 && !letters.is_empty() {
+            // each state which is not initial is the target of a transition from a state which comes before it
+            for position in initials_num..states_num {
+                let free_slots : Vec<(usize,Letter)> = order[..position].iter()
+                    .flat_map(|orig| letters.iter().map(move |letter| (*orig,*letter)))
+                    .filter(|(orig,letter)| !deterministic || !transitions[*orig].contains_key(letter))
+                    .collect();
+                let (orig,letter) = free_slots[self.draw_index(free_slots.len())];
+                transitions[orig].entry(letter).or_default().insert(order[position]);
+            }
+        }
+        // ***
+        for outgoing in transitions.iter_mut() {
+            for letter in &letters {
+                if deterministic {
+                    if !outgoing.contains_key(letter) && self.draw_with_probability(params.transition_density) {
+                        let targ = self.draw_index(states_num);
+                        outgoing.insert(*letter,HashSet::from([targ]));
+                    }
+                } else {
+                    for targ in 0..states_num {
+                        if self.draw_with_probability(params.transition_density) {
+                            outgoing.entry(*letter).or_default().insert(targ);
+                        }
+                    }
+                }
+            }
+        }
+        let mut epsilon_trans : Vec<HashSet<usize>> = vec![HashSet::new();states_num];
+        if with_epsilon {
+            for (orig,targets) in epsilon_trans.iter_mut().enumerate() {
+                for targ in 0..states_num {
+                    if orig != targ && self.draw_with_probability(params.epsilon_density) {
+                        targets.insert(targ);
+                    }
+                }
+            }
+        }
+        let mut states : Vec<usize> = (0..states_num).collect();
+        states.shuffle(&mut self.rng);
+        let finals : HashSet<usize> = states[..params.finals_num].iter().cloned().collect();
+        let mut nfait = AutNFAIT::from_raw(params.alphabet.clone(),initials,finals,transitions,epsilon_trans)?;
+        // ***
+        if params.ensure_trimmed {
+            loop {
+                let coaccessible = nfait.get_all_coaccessible_states();
+                let mut not_coaccessible : Vec<usize> = (0..states_num).filter(|x| !coaccessible.contains(x)).collect();
+                if not_coaccessible.is_empty() {
+                    break;
+                }
+                not_coaccessible.sort();
+                let new_final = not_coaccessible[self.draw_index(not_coaccessible.len())];
+                nfait.finals.insert(new_final);
+            }
+        }
+        if params.ensure_complete {
+            // adding transitions preserves accessibility and coaccessibility
+            for outgoing in nfait.transitions.iter_mut() {
+                for letter in &letters {
+                    if outgoing.get(letter).is_none_or(|targets| targets.is_empty()) {
+                        let targ = self.draw_index(states_num);
+                        outgoing.insert(*letter,HashSet::from([targ]));
+                    }
+                }
+            }
+        }
+        Ok(nfait)
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::random::generator::AutRandomGenerator;
+    use crate::random::params::RandomAutomatonParameters;
+    use crate::traits::access::AutAccessible;
+    use crate::traits::characterize::AutCharacterizable;
+
+    #[test]
+    fn generation_is_deterministic_test() {
+        let mut params = RandomAutomatonParameters::new(hashset!{'a','b','c'},12);
+        params.initials_num = 2;
+        params.finals_num = 3;
+        params.transition_density = 0.1;
+        params.epsilon_density = 0.05;
+        let nfait1 = AutRandomGenerator::from_seed(3).generate_nfait(&params).unwrap();
+        let nfait2 = AutRandomGenerator::from_seed(3).generate_nfait(&params).unwrap();
+        assert_eq!(nfait1.initials, nfait2.initials);
+        assert_eq!(nfait1.finals, nfait2.finals);
+        assert_eq!(nfait1.transitions, nfait2.transitions);
+        assert_eq!(nfait1.epsilon_trans, nfait2.epsilon_trans);
+    }
+
+    #[test]
+    fn generation_guarantees_test() {
+        let mut params = RandomAutomatonParameters::new(hashset!{'a','b'},15);
+        params.transition_density = 0.05;
+        params.ensure_trimmed = true;
+        params.ensure_complete = true;
+        for seed in 0..20 {
+            let mut generator = AutRandomGenerator::from_seed(seed);
+            let dfa = generator.generate_dfa(&params).unwrap();
+            assert_eq!(dfa.transitions.len(), 15);
+            assert!(dfa.is_trimmed());
+            assert!(dfa.is_complete());
+            let nfa = generator.generate_nfa(&params).unwrap();
+            assert!(nfa.is_trimmed());
+            assert!(nfa.is_complete());
+        }
+    }
+}
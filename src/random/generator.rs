@@ -0,0 +1,54 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+/// Generates random automata and regular expressions.
+///
+/// The generation is deterministic given the seed (for a given version of the crate and of its "rand" dependency)
+/// so that failing cases of property tests can be replayed.
+pub struct AutRandomGenerator {
+    pub(crate) rng : StdRng
+}
+
+impl AutRandomGenerator {
+
+    pub fn from_seed(seed : u64) -> Self {
+        AutRandomGenerator{rng:StdRng::seed_from_u64(seed)}
+    }
+
+    pub(crate) fn draw_with_probability(&mut self, probability : f64) -> bool {
+        self.rng.random_bool(probability)
+    }
+
+    pub(crate) fn draw_index(&mut self, below : usize) -> usize {
+        self.rng.random_range(0..below)
+    }
+
+}
+
+pub(crate) fn check_probability<Letter : AutLetter>(name : &str, probability : f64) -> Result<(),AutError<Letter>> {
+    if (0.0..=1.0).contains(&probability) {
+        Ok(())
+    } else {
+        Err(AutError::Other(format!("{} '{}' is not a probability", name, probability)))
+    }
+}
@@ -0,0 +1,85 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use crate::traits::letter::AutLetter;
+
+
+/// Parameters for the random generation of automata (see "AutRandomGenerator").
+#[derive(Debug, Clone)]
+pub struct RandomAutomatonParameters<Letter : AutLetter> {
+    pub alphabet : HashSet<Letter>,
+    /// number of states
+    pub states_num : usize,
+    /// probability of each transition being drawn i.e. of each (origin,letter) pair for a DFA
+    /// and of each (origin,letter,target) triple for a NFA
+    pub transition_density : f64,
+    /// number of initial states (ignored for a DFA which has a single initial state)
+    pub initials_num : usize,
+    /// number of final states
+    pub finals_num : usize,
+    /// probability of each immediate transition (origin,target) with distinct origin and target being drawn (only for a NFAIT)
+    pub epsilon_density : f64,
+    /// all states are made accessible by first drawing a spanning tree from the initial states
+    pub ensure_accessible : bool,
+    /// all states are made accessible and, as long as some states are not coaccessible,
+    /// one of them is made final (so there may be more final states than "finals_num")
+    pub ensure_trimmed : bool,
+    /// a transition towards a random target is added for each (origin,letter) pair without any
+    pub ensure_complete : bool
+}
+
+impl<Letter : AutLetter> RandomAutomatonParameters<Letter> {
+
+    /// Returns parameters for automata with a single initial and a single final state,
+    /// a transition density of 0.5, no immediate transitions and no other guarantee
+    pub fn new(alphabet : HashSet<Letter>, states_num : usize) -> Self {
+        RandomAutomatonParameters{
+            alphabet,
+            states_num,
+            transition_density:0.5,
+            initials_num:1,
+            finals_num:1,
+            epsilon_density:0.0,
+            ensure_accessible:false,
+            ensure_trimmed:false,
+            ensure_complete:false
+        }
+    }
+
+}
+
+
+/// Parameters for the random generation of regular expressions (see "AutRandomGenerator").
+#[derive(Debug, Clone)]
+pub struct RandomTermParameters<Letter : AutLetter> {
+    pub alphabet : HashSet<Letter>,
+    /// maximal depth of the term
+    pub max_depth : usize,
+    /// maximal number of nodes of the term
+    pub max_size : usize,
+    /// probability of a leaf being the empty word rather than a letter
+    pub epsilon_probability : f64
+}
+
+impl<Letter : AutLetter> RandomTermParameters<Letter> {
+
+    pub fn new(alphabet : HashSet<Letter>, max_depth : usize, max_size : usize) -> Self {
+        RandomTermParameters{alphabet,max_depth,max_size,epsilon_probability:0.1}
+    }
+
+}
@@ -0,0 +1,98 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::bre::term::TermBRE;
+use crate::random::generator::{check_probability, AutRandomGenerator};
+use crate::random::params::RandomTermParameters;
+use crate::traits::error::AutError;
+use crate::traits::letter::AutLetter;
+
+
+impl AutRandomGenerator {
+
+    /// Returns a random term with at most "max_depth" levels of operators and at most "max_size" nodes.
+    /// Unions and concatenations are built with "unite" and "concatenate" so they are flattened.
+    pub fn generate_term_bre<Letter : AutLetter>(&mut self,
+                                                 params : &RandomTermParameters<Letter>) -> Result<TermBRE<Letter>,AutError<Letter>> {
+        if params.max_size == 0 {
+            return Err(AutError::Other("cannot generate a term without nodes".to_string()));
+        }
+        check_probability("epsilon probability",params.epsilon_probability)?;
+        // letters are sorted so that the generation only depends on the seed
+        let mut letters : Vec<Letter> = params.alphabet.iter().cloned().collect();
+        letters.sort();
+        Ok(self.generate_sub_term(&letters,params.epsilon_probability,params.max_depth,params.max_size))
+    }
+
+    fn generate_sub_term<Letter : AutLetter>(&mut self,
+                                             letters : &[Letter],
+                                             epsilon_probability : f64,
+                                             depth : usize,
+                                             size : usize) -> TermBRE<Letter> {
+        if depth == 0 || size == 1 {
+            if letters.is_empty() || self.draw_with_probability(epsilon_probability) {
+                return TermBRE::Epsilon;
+            }
+            return TermBRE::Literal(letters[self.draw_index(letters.len())]);
+        }
+        // with only two nodes available, the only operator is the Kleene star
+        let operator = if size == 2 {0} else {self.draw_index(3)};
+        if operator == 0 {
+            let sub_term = self.generate_sub_term(letters,epsilon_probability,depth - 1,size - 1);
+            return TermBRE::Kleene(Box::new(sub_term));
+        }
+        // the remaining nodes are shared between the two sub-terms
+        let left_size = 1 + self.draw_index(size - 2);
+        let left = self.generate_sub_term(letters,epsilon_probability,depth - 1,left_size);
+        let right = self.generate_sub_term(letters,epsilon_probability,depth - 1,size - 1 - left_size);
+        if operator == 1 {
+            left.unite(right)
+        } else {
+            left.concatenate(right)
+        }
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashset;
+    use crate::bre::term::TermBRE;
+    use crate::random::generator::AutRandomGenerator;
+    use crate::random::params::RandomTermParameters;
+
+    fn get_depth(term : &TermBRE<char>) -> usize {
+        match term {
+            TermBRE::Union(sub_terms) => 1 + sub_terms.iter().map(get_depth).max().unwrap_or(0),
+            TermBRE::Concat(sub_terms) => 1 + sub_terms.iter().map(get_depth).max().unwrap_or(0),
+            TermBRE::Kleene(sub_term) => 1 + get_depth(sub_term),
+            _ => 0
+        }
+    }
+
+    #[test]
+    fn term_generation_test() {
+        let params = RandomTermParameters::new(hashset!{'a','b','c'},4,20);
+        for seed in 0..20 {
+            let term = AutRandomGenerator::from_seed(seed).generate_term_bre(&params).unwrap();
+            assert_eq!(term, AutRandomGenerator::from_seed(seed).generate_term_bre(&params).unwrap());
+            assert!(get_depth(&term) <= 4);
+            assert!(term.get_alphabet().is_subset(&params.alphabet));
+        }
+    }
+}
@@ -0,0 +1,135 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet};
+use maplit::hashset;
+
+use crate::bre::term::TermBRE;
+use crate::dfa::dfa::AutDFA;
+use crate::nfait::nfait::AutNFAIT;
+use crate::random::generator::AutRandomGenerator;
+use crate::random::params::{RandomAutomatonParameters, RandomTermParameters};
+use crate::traits::characterize::AutCharacterizable;
+
+
+/// Returns all the words over {a,b} of length at most *max_len*, by increasing length
+pub fn get_all_words(max_len : usize) -> Vec<Vec<char>> {
+    let mut words = vec![vec![]];
+    let mut previous = vec![vec![]];
+    for _ in 0..max_len {
+        let mut next = vec![];
+        for word in &previous {
+            for letter in ['a','b'] {
+                let mut new_word = word.clone();
+                new_word.push(letter);
+                next.push(new_word);
+            }
+        }
+        words.extend(next.iter().cloned());
+        previous = next;
+    }
+    words
+}
+
+/// Regular expressions over {a,b} denoting languages which are empty, universal, included in one another or incomparable
+pub const WITNESS_TEST_LANGUAGES : [&str;7] = ["∅","(a|b)*","ε","ab","(a|b)*ab(a|b)*","(a|b)*a","a*b*"];
+
+fn check_witness(query : &str,
+                 got : Option<Vec<char>>,
+                 expected_len : Option<usize>,
+                 is_valid : impl Fn(&[char]) -> bool) {
+    match (got,expected_len) {
+        (None,None) => {},
+        (Some(word),Some(len)) => {
+            assert_eq!(word.len(), len, "{} returned {:?} which is not a shortest witness", query, word);
+            assert!(is_valid(&word), "{} returned {:?} which is not a witness", query, word);
+        },
+        (got,expected_len) => {
+            panic!("{} returned {:?} while a shortest witness has length {:?}", query, got, expected_len);
+        }
+    }
+}
+
+/// Checks the witness words returned by the AutCharacterizable queries on *aut1* and *aut2* against the words of length at most *max_len*,
+/// which is supposed to be greater than the length of any shortest witness.
+/// The membership of a word to the language of an automaton is decided by *accepts*.
+pub fn check_witness_words<Aut : AutCharacterizable<char>>(aut1 : &Aut,
+                                                         aut2 : &Aut,
+                                                         accepts : &dyn Fn(&Aut,&[char]) -> bool,
+                                                         max_len : usize) {
+    let words = get_all_words(max_len);
+    let in1 : Vec<bool> = words.iter().map(|word| accepts(aut1,word)).collect();
+    let in2 : Vec<bool> = words.iter().map(|word| accepts(aut2,word)).collect();
+    // words are enumerated by increasing length
+    let shortest = |predicate : &dyn Fn(usize) -> bool| {
+        (0..words.len()).find(|&index| predicate(index)).map(|index| words[index].len())
+    };
+    check_witness("find_accepted_word",
+                  aut1.find_accepted_word(),
+                  shortest(&|index| in1[index]),
+                  |word| accepts(aut1,word));
+    check_witness("find_rejected_word",
+                  aut1.find_rejected_word(),
+                  shortest(&|index| !in1[index]),
+                  |word| !accepts(aut1,word));
+    check_witness("find_inclusion_counterexample",
+                  aut1.find_inclusion_counterexample(aut2),
+                  shortest(&|index| in2[index] && !in1[index]),
+                  |word| accepts(aut2,word) && !accepts(aut1,word));
+    check_witness("find_distinguishing_word",
+                  aut1.find_distinguishing_word(aut2),
+                  shortest(&|index| in1[index] != in2[index]),
+                  |word| accepts(aut1,word) != accepts(aut2,word));
+}
+
+/// Returns a DFA over *alphabet* accepting the words with an even number of 'a'
+pub fn get_even_a(alphabet : HashSet<char>) -> AutDFA<char> {
+    let transitions : Vec<HashMap<char,usize>> = vec![
+        alphabet.iter().map(|letter| (*letter, if *letter == 'a' {1} else {0})).collect(),
+        alphabet.iter().map(|letter| (*letter, if *letter == 'a' {0} else {1})).collect()
+    ];
+    AutDFA::<char>::from_raw(alphabet, 0, hashset!{0}, transitions).unwrap()
+}
+
+/// Calls *check* on *count* arrays of N terms generated from *params* by a generator seeded with *seed*
+pub fn for_each_random_terms<const N : usize>(seed : u64,
+                                              count : usize,
+                                              params : &RandomTermParameters<char>,
+                                              mut check : impl FnMut([TermBRE<char>;N])) {
+    let mut generator = AutRandomGenerator::from_seed(seed);
+    for _ in 0..count {
+        check(std::array::from_fn(|_| generator.generate_term_bre(params).unwrap()));
+    }
+}
+
+/// Calls *check* on *count* terms generated from *params* by a generator seeded with *seed*
+pub fn for_each_random_term(seed : u64,
+                            count : usize,
+                            params : &RandomTermParameters<char>,
+                            mut check : impl FnMut(TermBRE<char>)) {
+    for_each_random_terms(seed,count,params,|[term]| check(term));
+}
+
+/// Calls *check* on *count* NFAITs generated from *params* by a generator seeded with *seed*
+pub fn for_each_random_nfait(seed : u64,
+                             count : usize,
+                             params : &RandomAutomatonParameters<char>,
+                             mut check : impl FnMut(AutNFAIT<char>)) {
+    let mut generator = AutRandomGenerator::from_seed(seed);
+    for _ in 0..count {
+        check(generator.generate_nfait(params).unwrap());
+    }
+}
@@ -29,7 +29,7 @@ pub trait AutCharacterizable<Letter: AutLetter> {
     /// An automaton is said *universal* if there are no words that it doesn't accept
     fn is_universal(&self) -> bool;
 
-    /// 'self' contains 'other' if and only if each *word* accepted by 'self' is also accepted by 'other'
+    /// 'self' contains 'other' if and only if each *word* accepted by 'other' is also accepted by 'self'
     fn contains(&self, other : &Self) -> bool;
 
     /// 'self' equals 'other' if and only if they define the same language
@@ -37,4 +37,28 @@ pub trait AutCharacterizable<Letter: AutLetter> {
         self.contains(other) && other.contains(self)
     }
 
+    /// Returns a shortest *word* accepted by 'self' or None if 'self' is *empty*
+    fn find_accepted_word(&self) -> Option<Vec<Letter>>;
+
+    /// Returns a shortest *word* over the *alphabet* which is not accepted by 'self' or None if 'self' is *universal*
+    fn find_rejected_word(&self) -> Option<Vec<Letter>>;
+
+    /// Returns a shortest *word* accepted by 'other' but not by 'self' or None if 'self' contains 'other'
+    fn find_inclusion_counterexample(&self, other : &Self) -> Option<Vec<Letter>>;
+
+    /// Returns a shortest *word* accepted by exactly one of 'self' and 'other' or None if they define the same language
+    fn find_distinguishing_word(&self, other : &Self) -> Option<Vec<Letter>> {
+        match (self.find_inclusion_counterexample(other),other.find_inclusion_counterexample(self)) {
+            (None,None) => None,
+            (Some(word),None) | (None,Some(word)) => Some(word),
+            (Some(word1),Some(word2)) => {
+                if word2.len() < word1.len() {
+                    Some(word2)
+                } else {
+                    Some(word1)
+                }
+            }
+        }
+    }
+
 }
\ No newline at end of file
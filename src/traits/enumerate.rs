@@ -0,0 +1,28 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::dfa::algos::enumeration::ShortlexWordsIterator;
+use crate::traits::letter::AutLetter;
+
+
+pub trait AutEnumerable<Letter: AutLetter> {
+
+    /// Returns an iterator over the *words* accepted by the automaton in shortlex order (by length and then lexicographically).
+    /// The number and length of the enumerated *words* can be bounded with "with_max_count" and "with_max_length".
+    fn accepted_words(&self) -> ShortlexWordsIterator<Letter>;
+
+}
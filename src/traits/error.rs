@@ -28,7 +28,8 @@ pub enum AutError<Letter : AutLetter> {
     InvalidEpsilonTrans(usize,Option<usize>,usize),
     InvalidTransition(usize, Letter, usize, usize),
     Other(String),
-    OperationOnLanguagesOverDifferentAlphabets(HashSet<Letter>, HashSet<Letter>)
+    OperationOnLanguagesOverDifferentAlphabets(HashSet<Letter>, HashSet<Letter>),
+    InvalidSyntax(usize, String)
 }
 
 impl<Letter : AutLetter> fmt::Display for AutError<Letter> {
@@ -64,6 +65,9 @@ impl<Letter : AutLetter> fmt::Display for AutError<Letter> {
             },
             AutError::InvalidTransition(orig_stid, letter, targ_stid,num_states) => {
                 write!(f, "target of transition '{} -- {:?} -> {}' not in set of states '{:?}'",orig_stid, letter, targ_stid, 0..*num_states)
+            },
+            AutError::InvalidSyntax(position, msg) => {
+                write!(f, "invalid syntax at position {}: {}", position, msg)
             }
         }
     }
@@ -23,4 +23,5 @@ pub mod run;
 pub mod error;
 pub mod translate;
 pub mod repr;
-pub mod characterize;
\ No newline at end of file
+pub mod characterize;
+pub mod enumerate;
\ No newline at end of file
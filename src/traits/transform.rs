@@ -36,6 +36,12 @@ pub trait AutTransformable<Letter: AutLetter> : Sized {
     /// Returns an automaton that accepts a word if and only if this word is accepted by both *self* and *other*
     fn intersect(self, other: Self) -> Result<Self,AutError<Letter>>;
 
+    /// Returns an automaton that accepts a word if and only if this word is accepted by *self* but not by *other*
+    fn difference(self, other: Self) -> Result<Self,AutError<Letter>>;
+
+    /// Returns an automaton that accepts a word if and only if this word is accepted by exactly one of *self* and *other*
+    fn symmetric_difference(self, other: Self) -> Result<Self,AutError<Letter>>;
+
     /// Returns an automaton that accepts words which are interleavings of words of *self* and *other*
     fn interleave(self, other: Self) -> Result<Self,AutError<Letter>>;
 